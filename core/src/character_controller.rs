@@ -0,0 +1,145 @@
+use bevy::ecs::component::Component;
+use bevy::ecs::entity::Entity;
+use bevy::math::Vec3;
+use bevy::reflect::Reflect;
+
+/// Component that moves a kinematic body, sliding along the obstacles it encounters
+///
+/// It must be inserted on the same entity of a [`RigidBody::KinematicPositionBased`](crate::RigidBody::KinematicPositionBased)
+/// and of its [`CollisionShape`](crate::CollisionShape). That entity should not have a parent, as
+/// the resolved movement is applied to its `Transform`.
+///
+/// Each frame, the desired [`translation`](Self::translation) is resolved against the world by
+/// sweeping the collision shape:
+/// * When hitting an obstacle, the remaining movement slides along the obstacle surface.
+/// * Steps lower than [`step_height`](Self::step_height) are climbed.
+/// * Slopes steeper than [`max_slope`](Self::max_slope) are treated like walls.
+///
+/// The translation is then reset to zero, and [`is_grounded`](Self::is_grounded) and
+/// [`touching`](Self::touching) are updated to reflect the outcome of the movement.
+///
+/// Note that gravity is not applied to kinematic bodies. It is up to the game to include it in the
+/// desired translation.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::KinematicPositionBased)
+///         .insert(CollisionShape::Capsule { half_segment: 0.5, radius: 0.5 })
+///         .insert(CharacterController::default().with_step_height(0.3));
+/// }
+///
+/// fn walk(time: Res<Time>, mut controllers: Query<&mut CharacterController>) {
+///     for mut controller in controllers.iter_mut() {
+///         let gravity = if controller.is_grounded() { 0.0 } else { -9.81 };
+///         controller.translation = Vec3::new(5.0, gravity, 0.0) * time.delta_seconds();
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Clone, PartialEq, Reflect)]
+pub struct CharacterController {
+    /// Translation to apply during the next frame
+    ///
+    /// It is reset to zero once applied
+    pub translation: Vec3,
+
+    /// Direction considered as "up", used to detect grounds, slopes and steps
+    pub up: Vec3,
+
+    /// Maximum angle (in radians) between a slope and the ground that can be climbed
+    pub max_slope: f32,
+
+    /// Maximum height of the steps that can be climbed
+    pub step_height: f32,
+
+    /// Small distance to keep between the shape and the obstacles
+    pub offset: f32,
+
+    /// Maximum number of times the movement is resolved against obstacles in a single frame
+    pub max_iterations: u32,
+
+    grounded: bool,
+    touching: Vec<Entity>,
+}
+
+impl Default for CharacterController {
+    fn default() -> Self {
+        Self {
+            translation: Vec3::ZERO,
+            up: Vec3::Y,
+            max_slope: std::f32::consts::FRAC_PI_4,
+            step_height: 0.0,
+            offset: 0.01,
+            max_iterations: 4,
+            grounded: false,
+            touching: Vec::new(),
+        }
+    }
+}
+
+impl CharacterController {
+    /// Returns a new version with the given maximum slope angle (in radians)
+    #[must_use]
+    pub fn with_max_slope(mut self, max_slope: f32) -> Self {
+        self.max_slope = max_slope;
+        self
+    }
+
+    /// Returns a new version with the given maximum step height
+    #[must_use]
+    pub fn with_step_height(mut self, step_height: f32) -> Self {
+        self.step_height = step_height;
+        self
+    }
+
+    /// Returns true if the character stands on a ground that isn't too steep
+    #[must_use]
+    pub fn is_grounded(&self) -> bool {
+        self.grounded
+    }
+
+    /// Returns the collision shape entities touched during the last movement
+    #[must_use]
+    pub fn touching(&self) -> &[Entity] {
+        &self.touching
+    }
+
+    /// Returns true if a surface with the given normal is flat enough to stand on it
+    #[must_use]
+    pub fn is_walkable(&self, normal: Vec3) -> bool {
+        normal.angle_between(self.up) <= self.max_slope
+    }
+
+    /// Store the outcome of a movement
+    ///
+    /// This is called by the physics backend and should not be used by games
+    #[doc(hidden)]
+    pub fn set_outcome(&mut self, grounded: bool, touching: Vec<Entity>) {
+        self.grounded = grounded;
+        self.touching = touching;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flat_ground_is_walkable() {
+        assert!(CharacterController::default().is_walkable(Vec3::Y));
+    }
+
+    #[test]
+    fn wall_is_not_walkable() {
+        assert!(!CharacterController::default().is_walkable(Vec3::X));
+    }
+
+    #[test]
+    fn gentle_slope_is_walkable() {
+        assert!(CharacterController::default().is_walkable(Vec3::new(0.5, 1.0, 0.0).normalize()));
+    }
+}
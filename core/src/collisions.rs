@@ -0,0 +1,132 @@
+use bevy::ecs::component::Component;
+use bevy::ecs::entity::Entity;
+use bevy::reflect::Reflect;
+
+use crate::CollisionData;
+
+/// Component that lists the entities currently in contact with (or intersecting) the entity
+///
+/// It is opt-in, and kept up-to-date by heron after each physics step when inserted:
+/// * On the entity of a [`RigidBody`](crate::RigidBody), it lists the entities in contact with any
+///   of the collision shapes of the body.
+/// * On a child entity holding a [`CollisionShape`](crate::CollisionShape), it lists the entities
+///   in contact with that shape only.
+///
+/// Unlike the [`CollisionEvent`](crate::CollisionEvent), it always reflects the current state of
+/// the physics world, even if it is inserted after the contacts started. The contacts between the
+/// shapes of the same rigid body are ignored.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// # #[derive(Component)] struct Player;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(Collisions::default());
+/// }
+///
+/// fn log_contacts(query: Query<(Entity, &Collisions), With<Player>>) {
+///     for (entity, collisions) in query.iter() {
+///         for other in collisions.rigid_body_entities() {
+///             println!("{:?} is touching {:?}", entity, other);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Clone, Default, Eq, PartialEq, Reflect)]
+pub struct Collisions {
+    others: Vec<CollisionData>,
+}
+
+impl Collisions {
+    #[must_use]
+    #[allow(missing_docs)]
+    pub fn new(others: Vec<CollisionData>) -> Self {
+        Self { others }
+    }
+
+    /// Returns the collision data of the other collision shapes currently in contact
+    pub fn iter(&self) -> impl Iterator<Item = &CollisionData> {
+        self.others.iter()
+    }
+
+    /// Returns the entities of the other collision shapes currently in contact
+    pub fn collision_shape_entities(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.others
+            .iter()
+            .map(CollisionData::collision_shape_entity)
+    }
+
+    /// Returns the entities of the other rigid bodies currently in contact
+    ///
+    /// Each rigid body is returned once, even if several of its shapes are in contact.
+    pub fn rigid_body_entities(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.others
+            .iter()
+            .enumerate()
+            .filter(move |(index, data)| {
+                self.others[..*index]
+                    .iter()
+                    .all(|previous| previous.rigid_body_entity() != data.rigid_body_entity())
+            })
+            .map(|(_, data)| data.rigid_body_entity())
+    }
+
+    /// Returns true if the given entity (rigid body or collision shape) is currently in contact
+    #[must_use]
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.others.iter().any(|data| {
+            data.rigid_body_entity() == entity || data.collision_shape_entity() == entity
+        })
+    }
+
+    /// Returns the number of collision shapes currently in contact
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.others.len()
+    }
+
+    /// Returns true if nothing is currently in contact
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.others.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::CollisionLayers;
+
+    use super::*;
+
+    fn data(body: u32, shape: u32) -> CollisionData {
+        CollisionData::new(
+            Entity::from_raw(body),
+            Entity::from_raw(shape),
+            CollisionLayers::default(),
+        )
+    }
+
+    #[test]
+    fn rigid_body_entities_are_unique() {
+        let collisions = Collisions::new(vec![data(1, 2), data(1, 3), data(4, 4)]);
+
+        let bodies: Vec<Entity> = collisions.rigid_body_entities().collect();
+
+        assert_eq!(bodies, vec![Entity::from_raw(1), Entity::from_raw(4)]);
+        assert_eq!(collisions.len(), 3);
+    }
+
+    #[test]
+    fn contains_rigid_bodies_and_collision_shapes() {
+        let collisions = Collisions::new(vec![data(1, 2)]);
+
+        assert!(collisions.contains(Entity::from_raw(1)));
+        assert!(collisions.contains(Entity::from_raw(2)));
+        assert!(!collisions.contains(Entity::from_raw(3)));
+    }
+}
@@ -88,3 +88,96 @@ impl RotationConstraints {
         }
     }
 }
+
+/// Component that restrict what translations can be caused by forces.
+///
+/// It must be inserted on the same entity of a [`RigidBody`](crate::RigidBody)
+///
+/// When all the axes are locked, linear velocity may still be applied programmatically. This only
+/// restrict how the position can change when forces are applied.
+///
+/// When only some of the axes are locked, the velocity and the movement along the locked axes are
+/// cancelled after each physics step.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+///
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(TranslationConstraints::restrict_to_x_only()); // Only move along the x axis
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, Reflect)]
+pub struct TranslationConstraints {
+    /// Set to false to prevent translations along the x axis
+    pub allow_x: bool,
+
+    /// Set to false to prevent translations along the y axis
+    pub allow_y: bool,
+
+    /// Set to false to prevent translations along the z axis
+    pub allow_z: bool,
+}
+
+impl Default for TranslationConstraints {
+    fn default() -> Self {
+        Self::allow()
+    }
+}
+
+impl TranslationConstraints {
+    /// Lock translations along all axes
+    #[must_use]
+    pub fn lock() -> Self {
+        Self {
+            allow_x: false,
+            allow_y: false,
+            allow_z: false,
+        }
+    }
+
+    /// Allow translations along all axes
+    #[must_use]
+    pub fn allow() -> Self {
+        Self {
+            allow_x: true,
+            allow_y: true,
+            allow_z: true,
+        }
+    }
+
+    /// Allow translation along the x axis only (and prevent moving along the other axes)
+    #[must_use]
+    pub fn restrict_to_x_only() -> Self {
+        Self {
+            allow_x: true,
+            allow_y: false,
+            allow_z: false,
+        }
+    }
+
+    /// Allow translation along the y axis only (and prevent moving along the other axes)
+    #[must_use]
+    pub fn restrict_to_y_only() -> Self {
+        Self {
+            allow_x: false,
+            allow_y: true,
+            allow_z: false,
+        }
+    }
+
+    /// Allow translation along the z axis only (and prevent moving along the other axes)
+    #[must_use]
+    pub fn restrict_to_z_only() -> Self {
+        Self {
+            allow_x: false,
+            allow_y: false,
+            allow_z: true,
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use bevy::ecs::component::Component;
+use bevy::reflect::Reflect;
+
+use crate::EntityCollisionEvent;
+
+/// Component that receives the collision events of the entity it is inserted on
+///
+/// Once inserted on the entity of a [`RigidBody`](crate::RigidBody) or of a
+/// [`CollisionShape`](crate::CollisionShape), heron pushes in it every
+/// [`CollisionEvent`](crate::CollisionEvent) the entity takes part in, seen from the entity (see
+/// [`EntityCollisionEvent`]). This avoids reading and filtering the global event stream in every
+/// system that reacts to the collisions of a few entities.
+///
+/// The events are pushed at the end of each frame, and stay in the queue until they are drained.
+///
+/// The collision events still have to be activated with the
+/// [`ActiveCollisionEvents`](crate::ActiveCollisionEvents) component, on the collision shape or on
+/// the shapes it collides with.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// # #[derive(Component)] struct Player;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(Player)
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(ActiveCollisionEvents::default())
+///         .insert(CollisionEventQueue::default());
+/// }
+///
+/// fn hurt_player(mut players: Query<&mut CollisionEventQueue, With<Player>>) {
+///     for mut queue in players.iter_mut() {
+///         for event in queue.drain().filter(EntityCollisionEvent::is_started) {
+///             println!("The player was hit by {:?}", event.other().rigid_body_entity());
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Clone, Default, Eq, PartialEq, Reflect)]
+pub struct CollisionEventQueue {
+    events: Vec<EntityCollisionEvent>,
+}
+
+impl CollisionEventQueue {
+    /// Add an event at the end of the queue
+    pub fn push(&mut self, event: EntityCollisionEvent) {
+        self.events.push(event);
+    }
+
+    /// Returns the events in the queue, in the order they were fired, without removing them
+    pub fn iter(&self) -> impl Iterator<Item = &EntityCollisionEvent> {
+        self.events.iter()
+    }
+
+    /// Removes and returns the events in the queue, in the order they were fired
+    pub fn drain(&mut self) -> impl Iterator<Item = EntityCollisionEvent> + '_ {
+        self.events.drain(..)
+    }
+
+    /// Removes all events from the queue
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+
+    /// Returns the number of events in the queue
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns true if there is no event in the queue
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
@@ -1,9 +1,15 @@
+use bevy::ecs::component::Component;
 use bevy::ecs::entity::Entity;
+use bevy::math::Vec3;
+use bevy::reflect::{FromReflect, Reflect};
 
-use crate::CollisionLayers;
+use crate::{CollisionLayers, CollisionShapeError};
 
 /// An event fired when the collision state between two entities changed
 ///
+/// The events are only fired for the pairs of collision shapes where at least one of the two shapes
+/// activates them with the [`ActiveCollisionEvents`] component.
+///
 /// # Example
 ///
 /// ```
@@ -31,14 +37,188 @@ pub enum CollisionEvent {
     Stopped(CollisionData, CollisionData),
 }
 
-/// Collision data concerning one of the two entity that collided
+/// Component that activates the collision events for a collision shape
+///
+/// It must be inserted on the same entity of a [`CollisionShape`](crate::CollisionShape).
+///
+/// No [`CollisionEvent`] (nor [`CollisionContactEvent`]) is fired for the collision shapes without
+/// this component, which avoids paying for events nobody reads in scenes with many bodies. A pair
+/// of collision shapes fires events if at least one of the two shapes activates them.
+///
+/// The default value activates all events.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Sensor)
+///         .insert(CollisionShape::Sphere { radius: 2.0 })
+///         .insert(ActiveCollisionEvents::intersections()); // e.g. for a trigger zone
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, Eq, PartialEq, Reflect)]
+pub struct ActiveCollisionEvents {
+    /// Fire the events of the contacts between two non-sensor shapes
+    pub contacts: bool,
+
+    /// Fire the events of the intersections involving a sensor
+    pub intersections: bool,
+}
+
+impl Default for ActiveCollisionEvents {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl ActiveCollisionEvents {
+    /// Activate only the events of the contacts between two non-sensor shapes
+    #[must_use]
+    pub fn contacts() -> Self {
+        Self {
+            contacts: true,
+            intersections: false,
+        }
+    }
+
+    /// Activate only the events of the intersections involving a sensor
+    #[must_use]
+    pub fn intersections() -> Self {
+        Self {
+            contacts: false,
+            intersections: true,
+        }
+    }
+
+    /// Activate all events
+    #[must_use]
+    pub fn all() -> Self {
+        Self {
+            contacts: true,
+            intersections: true,
+        }
+    }
+}
+
+/// A [`CollisionEvent`] seen from one of the two entities that collided
+///
+/// Each variant contains the data of the entity itself, followed by the data of the other entity.
+/// These events are received in a [`CollisionEventQueue`](crate::CollisionEventQueue), or can be
+/// obtained with [`CollisionEvent::for_entity`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Reflect, FromReflect)]
+pub enum EntityCollisionEvent {
+    /// The entity started to collide with the other entity
+    Started(CollisionData, CollisionData),
+
+    /// The entity no longer collides with the other entity
+    Stopped(CollisionData, CollisionData),
+}
+
+/// An event fired when a rigid body falls asleep or wakes up
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn detect_rest(mut events: EventReader<SleepEvent>) {
+///     for event in events.iter() {
+///         match event {
+///             SleepEvent::FellAsleep(entity) => println!("Entity {:?} came to rest", entity),
+///             SleepEvent::WokeUp(entity) => println!("Entity {:?} moves again", entity),
+///         }
+///     }
+/// }
+/// ```
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SleepEvent {
+    /// The rigid body of the entity fell asleep
+    FellAsleep(Entity),
+
+    /// The rigid body of the entity woke up
+    WokeUp(Entity),
+}
+
+/// An event fired when the [`CollisionShape`](crate::CollisionShape) of an entity could not be
+/// built
+///
+/// The entity also gets an [`InvalidCollisionShape`](crate::InvalidCollisionShape) component.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn report_invalid_shapes(mut events: EventReader<CollisionShapeErrorEvent>) {
+///     for event in events.iter() {
+///         println!("Invalid collision shape on {:?}: {}", event.entity, event.error);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CollisionShapeErrorEvent {
+    /// Entity of the collision shape
+    pub entity: Entity,
+
+    /// Reason why the collision shape could not be built
+    pub error: CollisionShapeError,
+}
+
+/// Collision data concerning one of the two entity that collided
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Reflect, FromReflect)]
 pub struct CollisionData {
     rigid_body_entity: Entity,
     collision_shape_entity: Entity,
     collision_layers: CollisionLayers,
 }
 
+/// An event fired alongside [`CollisionEvent::Started`] when two non-sensor shapes start to be in
+/// contact, with the detail of each contact point
+///
+/// The data is ordered the same way as in the corresponding [`CollisionEvent`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn_impact_effects(mut events: EventReader<CollisionContactEvent>) {
+///     for event in events.iter() {
+///         for contact in event.points() {
+///             println!("Impact at {} with an impulse of {}", contact.point, contact.impulse)
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CollisionContactEvent {
+    data1: CollisionData,
+    data2: CollisionData,
+    points: Vec<ContactPoint>,
+}
+
+/// A contact point between two collision shapes
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ContactPoint {
+    /// Position of the contact point, in world space, on the shape of the first entity
+    pub point: Vec3,
+
+    /// Contact normal, in world space, pointing from the first entity toward the second one
+    pub normal: Vec3,
+
+    /// How deep the two shapes penetrate each other at this point
+    ///
+    /// It is negative if the shapes are not (yet) penetrating each other.
+    pub penetration_depth: f32,
+
+    /// Impulse applied by the solver at this point, during the physics step in which the contact
+    /// started
+    pub impulse: f32,
+}
+
 impl From<CollisionEvent> for (CollisionData, CollisionData) {
     fn from(event: CollisionEvent) -> Self {
         event.data()
@@ -95,6 +275,115 @@ impl CollisionEvent {
             }
         }
     }
+
+    /// Returns the event seen from the given entity (rigid body or collision shape), or `None` if
+    /// the entity isn't involved in the collision
+    #[must_use]
+    pub fn for_entity(&self, entity: Entity) -> Option<EntityCollisionEvent> {
+        let (d1, d2) = self.data();
+        let (this, other) = if d1.is_entity(entity) {
+            (d1, d2)
+        } else if d2.is_entity(entity) {
+            (d2, d1)
+        } else {
+            return None;
+        };
+
+        Some(match self {
+            CollisionEvent::Started(_, _) => EntityCollisionEvent::Started(this, other),
+            CollisionEvent::Stopped(_, _) => EntityCollisionEvent::Stopped(this, other),
+        })
+    }
+}
+
+impl EntityCollisionEvent {
+    /// Returns true if the event represent the "start" of a collision
+    #[must_use]
+    pub fn is_started(&self) -> bool {
+        matches!(self, EntityCollisionEvent::Started(_, _))
+    }
+
+    /// Returns true if the event represent the "end" of a collision
+    #[must_use]
+    pub fn is_stopped(&self) -> bool {
+        matches!(self, EntityCollisionEvent::Stopped(_, _))
+    }
+
+    /// Returns the data of the entity that received the event
+    #[must_use]
+    pub fn this(&self) -> CollisionData {
+        match self {
+            EntityCollisionEvent::Started(this, _) | EntityCollisionEvent::Stopped(this, _) => {
+                *this
+            }
+        }
+    }
+
+    /// Returns the data of the other entity involved in the collision
+    #[must_use]
+    pub fn other(&self) -> CollisionData {
+        match self {
+            EntityCollisionEvent::Started(_, other) | EntityCollisionEvent::Stopped(_, other) => {
+                *other
+            }
+        }
+    }
+}
+
+impl CollisionContactEvent {
+    #[must_use]
+    #[allow(missing_docs)]
+    pub fn new(data1: CollisionData, data2: CollisionData, points: Vec<ContactPoint>) -> Self {
+        Self {
+            data1,
+            data2,
+            points,
+        }
+    }
+
+    /// Returns the data for the two entities that collided
+    #[must_use]
+    pub fn data(&self) -> (CollisionData, CollisionData) {
+        (self.data1, self.data2)
+    }
+
+    /// Returns the entities containing the [`CollisionShape`](crate::CollisionShape) involved in the collision
+    #[must_use]
+    pub fn collision_shape_entities(&self) -> (Entity, Entity) {
+        (
+            self.data1.collision_shape_entity,
+            self.data2.collision_shape_entity,
+        )
+    }
+
+    /// Returns the entities containing the [`RigidBody`](crate::RigidBody) involved in the collision
+    #[must_use]
+    pub fn rigid_body_entities(&self) -> (Entity, Entity) {
+        (self.data1.rigid_body_entity, self.data2.rigid_body_entity)
+    }
+
+    /// Returns the contact points
+    #[must_use]
+    pub fn points(&self) -> &[ContactPoint] {
+        &self.points
+    }
+
+    /// Returns the sum of the impulses applied at each contact point
+    #[must_use]
+    pub fn total_impulse(&self) -> f32 {
+        self.points.iter().map(|p| p.impulse).sum()
+    }
+
+    /// Returns the deepest penetration depth among the contact points
+    ///
+    /// Returns `None` if there is no contact point
+    #[must_use]
+    pub fn max_penetration_depth(&self) -> Option<f32> {
+        self.points
+            .iter()
+            .map(|p| p.penetration_depth)
+            .reduce(f32::max)
+    }
 }
 
 impl CollisionData {
@@ -129,4 +418,8 @@ impl CollisionData {
     pub fn collision_layers(&self) -> CollisionLayers {
         self.collision_layers
     }
+
+    fn is_entity(&self, entity: Entity) -> bool {
+        self.rigid_body_entity == entity || self.collision_shape_entity == entity
+    }
 }
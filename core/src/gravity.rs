@@ -1,5 +1,6 @@
 use bevy::ecs::component::Component;
 use bevy::math::{Vec2, Vec3};
+use bevy::reflect::Reflect;
 
 /// Resource that defines world's gravity.
 ///
@@ -51,3 +52,171 @@ impl From<Gravity> for Vec3 {
         g.vector()
     }
 }
+
+/// Component that scales the gravity applied to a rigid body
+///
+/// It must be inserted on the same entity of a [`RigidBody`](crate::RigidBody).
+///
+/// A scale of zero makes the body float, and a negative scale makes it fall upward.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(GravityScale(0.0)); // The body floats
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect)]
+pub struct GravityScale(pub f32);
+
+impl Default for GravityScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Component that defines a region in which the gravity is different
+///
+/// It must be inserted on the same entity of a [`CollisionShape`](crate::CollisionShape)
+/// that is a sensor (i.e. a [`RigidBody::Sensor`](crate::RigidBody::Sensor) or a
+/// [`SensorShape`](crate::SensorShape)). The dynamic bodies intersecting that shape are
+/// affected by the zone.
+///
+/// The zone gravity is scaled by the [`GravityScale`] of the affected bodies.
+///
+/// If a body is in more than one zone, the gravity of the replacing zones overrides the world
+/// [`Gravity`], and the gravity of all the other zones is added to it.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     // A planet attracting the bodies around it
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Sensor)
+///         .insert(CollisionShape::Sphere { radius: 100.0 })
+///         .insert(GravityZone::attractor(9.81));
+///
+///     // A water current pushing the bodies to the right
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Sensor)
+///         .insert(CollisionShape::Cuboid { half_extends: Vec3::new(10.0, 2.0, 0.0), border_radius: None })
+///         .insert(GravityZone::add(Vec3::X * 2.0));
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect)]
+pub struct GravityZone {
+    /// Gravity field of the zone
+    pub field: GravityField,
+
+    /// If true, the zone gravity replaces the world gravity. Otherwise it is added to it.
+    pub replace: bool,
+}
+
+/// Gravity field of a [`GravityZone`]
+#[derive(Debug, Copy, Clone, PartialEq, Reflect)]
+pub enum GravityField {
+    /// Same acceleration everywhere in the zone
+    Uniform(Vec3),
+
+    /// Acceleration of the given magnitude toward the origin of the zone entity
+    Attractor(f32),
+}
+
+impl GravityZone {
+    /// Zone with a uniform gravity that replaces the world gravity
+    #[must_use]
+    pub fn replace(gravity: Vec3) -> Self {
+        Self {
+            field: GravityField::Uniform(gravity),
+            replace: true,
+        }
+    }
+
+    /// Zone with a uniform gravity that is added to the world gravity
+    #[must_use]
+    pub fn add(gravity: Vec3) -> Self {
+        Self {
+            field: GravityField::Uniform(gravity),
+            replace: false,
+        }
+    }
+
+    /// Zone that attracts the bodies toward its origin, replacing the world gravity
+    #[must_use]
+    pub fn attractor(strength: f32) -> Self {
+        Self {
+            field: GravityField::Attractor(strength),
+            replace: true,
+        }
+    }
+
+    /// Returns the gravity (unscaled) applied by this zone to a body at the given position
+    #[must_use]
+    pub fn gravity_at(&self, zone_origin: Vec3, position: Vec3) -> Vec3 {
+        match self.field {
+            GravityField::Uniform(gravity) => gravity,
+            GravityField::Attractor(strength) => {
+                (zone_origin - position).normalize_or_zero() * strength
+            }
+        }
+    }
+}
+
+/// Returns the resulting gravity (unscaled) for a body affected by the given zones
+///
+/// Each zone is given with the gravity it applies at the position of the body.
+#[must_use]
+pub fn combine_gravity(world_gravity: Vec3, zones: impl IntoIterator<Item = (bool, Vec3)>) -> Vec3 {
+    let mut replaced: Option<Vec3> = None;
+    let mut added = Vec3::ZERO;
+
+    for (replace, gravity) in zones {
+        if replace {
+            replaced = Some(replaced.unwrap_or(Vec3::ZERO) + gravity);
+        } else {
+            added += gravity;
+        }
+    }
+
+    replaced.unwrap_or(world_gravity) + added
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attractor_pulls_toward_zone_origin() {
+        let zone = GravityZone::attractor(2.0);
+        assert_eq!(zone.gravity_at(Vec3::ZERO, Vec3::X * 10.0), -Vec3::X * 2.0);
+    }
+
+    #[test]
+    fn world_gravity_is_kept_without_zones() {
+        assert_eq!(combine_gravity(-Vec3::Y, []), -Vec3::Y);
+    }
+
+    #[test]
+    fn added_zones_are_added_to_world_gravity() {
+        assert_eq!(
+            combine_gravity(-Vec3::Y, [(false, Vec3::X)]),
+            Vec3::new(1.0, -1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn replacing_zones_override_world_gravity() {
+        assert_eq!(
+            combine_gravity(-Vec3::Y, [(true, Vec3::X), (false, Vec3::Z)]),
+            Vec3::new(1.0, 0.0, 1.0)
+        );
+    }
+}
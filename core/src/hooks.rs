@@ -0,0 +1,72 @@
+use bevy::ecs::component::Component;
+use bevy::reflect::Reflect;
+
+/// Component that activates the user-defined physics hooks for a collision shape
+///
+/// It must be inserted on the same entity of a [`CollisionShape`](crate::CollisionShape).
+///
+/// The physics hooks themselves are defined by the physics backend. A pair of collision shapes
+/// is passed to the hooks if at least one of the two shapes activates the corresponding hook.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Static)
+///         .insert(CollisionShape::Cuboid { half_extends: Vec3::new(5.0, 0.5, 0.0), border_radius: None })
+///         .insert(ActiveCollisionHooks::modify_solver_contacts()); // e.g. for a one-way platform
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, Default, Eq, PartialEq, Reflect)]
+pub struct ActiveCollisionHooks {
+    /// Ask the hooks whether a contact pair should be solved
+    pub filter_contact_pairs: bool,
+
+    /// Ask the hooks whether an intersection pair (involving a sensor) should be reported
+    pub filter_intersection_pairs: bool,
+
+    /// Let the hooks modify the contacts before they are solved
+    pub modify_solver_contacts: bool,
+}
+
+impl ActiveCollisionHooks {
+    /// Activate only the filtering of contact pairs
+    #[must_use]
+    pub fn filter_contact_pairs() -> Self {
+        Self {
+            filter_contact_pairs: true,
+            ..Self::default()
+        }
+    }
+
+    /// Activate only the filtering of intersection pairs
+    #[must_use]
+    pub fn filter_intersection_pairs() -> Self {
+        Self {
+            filter_intersection_pairs: true,
+            ..Self::default()
+        }
+    }
+
+    /// Activate only the modification of solver contacts
+    #[must_use]
+    pub fn modify_solver_contacts() -> Self {
+        Self {
+            modify_solver_contacts: true,
+            ..Self::default()
+        }
+    }
+
+    /// Activate all hooks
+    #[must_use]
+    pub fn all() -> Self {
+        Self {
+            filter_contact_pairs: true,
+            filter_intersection_pairs: true,
+            modify_solver_contacts: true,
+        }
+    }
+}
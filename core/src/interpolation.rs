@@ -0,0 +1,47 @@
+use bevy::ecs::component::Component;
+use bevy::reflect::Reflect;
+
+/// Component that smooths the rendered transform of a rigid body between physics steps
+///
+/// It must be inserted on the same entity of a [`RigidBody`](crate::RigidBody).
+///
+/// When the physics steps run at a fixed rate (e.g. [`PhysicsSteps::from_steps_per_seconds`](crate::PhysicsSteps::from_steps_per_seconds)),
+/// the position of the bodies only changes on the frames that run a physics step. That makes the
+/// movement stutter when the frame rate differs from the physics rate.
+///
+/// With this component, the `Transform` is updated every frame, according to the time elapsed
+/// since the last physics step. The physics world itself is not affected.
+///
+/// It has no effect if the physics steps run every frame.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(TransformInterpolation::Interpolate);
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, Eq, PartialEq, Reflect)]
+pub enum TransformInterpolation {
+    /// Interpolate between the two last physics steps
+    ///
+    /// The rendered transform is always accurate, but lags up to one physics step behind.
+    Interpolate,
+
+    /// Extrapolate from the two last physics steps
+    ///
+    /// The rendered transform doesn't lag behind, but may slightly overshoot when the body changes
+    /// direction.
+    Extrapolate,
+}
+
+impl Default for TransformInterpolation {
+    fn default() -> Self {
+        Self::Interpolate
+    }
+}
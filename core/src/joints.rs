@@ -0,0 +1,176 @@
+use bevy::ecs::component::Component;
+use bevy::ecs::entity::Entity;
+use bevy::math::Vec3;
+use bevy::reflect::Reflect;
+
+/// Component that connects two rigid bodies with a joint
+///
+/// It should be inserted in its own entity, and it references the two entities containing the
+/// [`RigidBody`](crate::RigidBody) to connect.
+///
+/// The joint is created as soon as both rigid bodies exist in the physics world, and it is removed
+/// when the component is removed or when one of the two rigid bodies is removed.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     let anchor = commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Static)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .id();
+///
+///     let pendulum = commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .id();
+///
+///     commands.spawn().insert(
+///         Joint::ball(anchor, pendulum)
+///             .with_anchors(Vec3::ZERO, Vec3::Y * 10.0)
+///     );
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect)]
+pub struct Joint {
+    /// Entity of the first rigid body
+    pub body1: Entity,
+
+    /// Entity of the second rigid body
+    pub body2: Entity,
+
+    /// Anchor point, in the local space of the first rigid body
+    pub anchor1: Vec3,
+
+    /// Anchor point, in the local space of the second rigid body
+    pub anchor2: Vec3,
+
+    /// Kind of joint, defining what relative motion is allowed between the two bodies
+    pub kind: JointKind,
+}
+
+/// Kind of [`Joint`], defining what relative motion is allowed between the two rigid bodies
+#[derive(Debug, Copy, Clone, PartialEq, Reflect)]
+pub enum JointKind {
+    /// No relative motion is allowed at all. The bodies are welded together.
+    Fixed,
+
+    /// Only relative rotations around the anchor points are allowed.
+    Ball,
+
+    /// Only relative rotations around the given axes are allowed.
+    ///
+    /// In 2d, the axes are ignored and the rotation happens around the `z` axis.
+    Revolute {
+        /// Rotation axis, in the local space of the first rigid body
+        axis1: Vec3,
+        /// Rotation axis, in the local space of the second rigid body
+        axis2: Vec3,
+    },
+
+    /// Only relative translations along the given axes are allowed.
+    Prismatic {
+        /// Translation axis, in the local space of the first rigid body
+        axis1: Vec3,
+        /// Translation axis, in the local space of the second rigid body
+        axis2: Vec3,
+    },
+}
+
+impl Default for JointKind {
+    fn default() -> Self {
+        Self::Fixed
+    }
+}
+
+impl Joint {
+    /// Create a joint of the given kind between two rigid bodies, anchored at their origins
+    #[must_use]
+    pub fn new(body1: Entity, body2: Entity, kind: JointKind) -> Self {
+        Self {
+            body1,
+            body2,
+            anchor1: Vec3::ZERO,
+            anchor2: Vec3::ZERO,
+            kind,
+        }
+    }
+
+    /// Create a [`JointKind::Fixed`] joint between two rigid bodies
+    #[must_use]
+    pub fn fixed(body1: Entity, body2: Entity) -> Self {
+        Self::new(body1, body2, JointKind::Fixed)
+    }
+
+    /// Create a [`JointKind::Ball`] joint between two rigid bodies
+    #[must_use]
+    pub fn ball(body1: Entity, body2: Entity) -> Self {
+        Self::new(body1, body2, JointKind::Ball)
+    }
+
+    /// Create a [`JointKind::Revolute`] joint between two rigid bodies, rotating around the given
+    /// axis (expressed in the local space of both bodies)
+    #[must_use]
+    pub fn revolute(body1: Entity, body2: Entity, axis: Vec3) -> Self {
+        Self::new(
+            body1,
+            body2,
+            JointKind::Revolute {
+                axis1: axis,
+                axis2: axis,
+            },
+        )
+    }
+
+    /// Create a [`JointKind::Prismatic`] joint between two rigid bodies, sliding along the given
+    /// axis (expressed in the local space of both bodies)
+    #[must_use]
+    pub fn prismatic(body1: Entity, body2: Entity, axis: Vec3) -> Self {
+        Self::new(
+            body1,
+            body2,
+            JointKind::Prismatic {
+                axis1: axis,
+                axis2: axis,
+            },
+        )
+    }
+
+    /// Returns a new version of this joint with the given local anchor points
+    #[must_use]
+    pub fn with_anchors(mut self, anchor1: Vec3, anchor2: Vec3) -> Self {
+        self.anchor1 = anchor1;
+        self.anchor2 = anchor2;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn revolute_uses_same_axis_on_both_bodies() {
+        let joint = Joint::revolute(Entity::from_raw(0), Entity::from_raw(1), Vec3::X);
+        assert_eq!(
+            joint.kind,
+            JointKind::Revolute {
+                axis1: Vec3::X,
+                axis2: Vec3::X
+            }
+        );
+    }
+
+    #[test]
+    fn with_anchors_keeps_bodies_and_kind() {
+        let joint =
+            Joint::ball(Entity::from_raw(0), Entity::from_raw(1)).with_anchors(Vec3::X, Vec3::Y);
+        assert_eq!(joint.body1, Entity::from_raw(0));
+        assert_eq!(joint.body2, Entity::from_raw(1));
+        assert_eq!(joint.anchor1, Vec3::X);
+        assert_eq!(joint.anchor2, Vec3::Y);
+        assert_eq!(joint.kind, JointKind::Ball);
+    }
+}
@@ -10,19 +10,46 @@ use std::sync::Arc;
 use bevy::ecs::schedule::ShouldRun;
 use bevy::prelude::*;
 
-pub use constraints::RotationConstraints;
-pub use events::{CollisionData, CollisionEvent};
-pub use gravity::Gravity;
+pub use character_controller::CharacterController;
+pub use collisions::Collisions;
+pub use constraints::{RotationConstraints, TranslationConstraints};
+pub use event_queue::CollisionEventQueue;
+pub use events::{
+    ActiveCollisionEvents, CollisionContactEvent, CollisionData, CollisionEvent,
+    CollisionShapeErrorEvent, ContactPoint, EntityCollisionEvent, SleepEvent,
+};
+pub use gravity::{combine_gravity, Gravity, GravityField, GravityScale, GravityZone};
+pub use hooks::ActiveCollisionHooks;
+pub use interpolation::TransformInterpolation;
+pub use joints::{Joint, JointKind};
 pub use layers::{CollisionLayers, PhysicsLayer};
+pub use mass::{ComputedMassProperties, MassProperties};
+#[cfg(feature = "collision-from-mesh")]
+pub use mesh::{MeshCollisionShape, MeshShapeKind};
 pub use physics_time::PhysicsTime;
+pub use physics_world::PhysicsWorldId;
+pub use shape_error::{CollisionShapeError, InvalidCollisionShape};
+pub use sleep::Sleeping;
 pub use step::{PhysicsStepDuration, PhysicsSteps};
-pub use velocity::{Acceleration, AxisAngle, Damping, Velocity};
+pub use velocity::{Acceleration, AxisAngle, Damping, Impulse, Velocity};
 
+mod character_controller;
+mod collisions;
 mod constraints;
+mod event_queue;
 mod events;
 mod gravity;
+mod hooks;
+mod interpolation;
+mod joints;
 mod layers;
+mod mass;
+#[cfg(feature = "collision-from-mesh")]
+mod mesh;
 mod physics_time;
+mod physics_world;
+mod shape_error;
+mod sleep;
 mod step;
 pub mod utils;
 mod velocity;
@@ -64,18 +91,41 @@ impl Plugin for CorePlugin {
             .init_resource::<PhysicsTime>()
             .init_resource::<PhysicsSteps>()
             .register_type::<CollisionShape>()
+            .register_type::<CompoundShapeChild>()
             .register_type::<RigidBody>()
             .register_type::<PhysicMaterial>()
+            .register_type::<CombineRule>()
             .register_type::<Velocity>()
             .register_type::<Acceleration>()
             .register_type::<Damping>()
+            .register_type::<Impulse>()
             .register_type::<RotationConstraints>()
+            .register_type::<TranslationConstraints>()
             .register_type::<CollisionLayers>()
+            .register_type::<CollisionData>()
+            .register_type::<Collisions>()
+            .register_type::<EntityCollisionEvent>()
+            .register_type::<CollisionEventQueue>()
             .register_type::<SensorShape>()
+            .register_type::<ContinuousCollisionDetection>()
+            .register_type::<Joint>()
+            .register_type::<CharacterController>()
+            .register_type::<MassProperties>()
+            .register_type::<ComputedMassProperties>()
+            .register_type::<TransformInterpolation>()
+            .register_type::<ActiveCollisionHooks>()
+            .register_type::<ActiveCollisionEvents>()
+            .register_type::<Sleeping>()
+            .register_type::<GravityScale>()
+            .register_type::<GravityZone>()
+            .register_type::<PhysicsWorldId>()
             .add_system_to_stage(CoreStage::First, PhysicsSteps::update.system())
             .add_stage_before(CoreStage::PostUpdate, crate::stage::ROOT, {
                 Schedule::default().with_stage(crate::stage::UPDATE, SystemStage::parallel())
             });
+
+        #[cfg(feature = "collision-from-mesh")]
+        app.register_type::<MeshCollisionShape>();
     }
 }
 
@@ -106,6 +156,12 @@ impl CustomCollisionShape {
         Self(Arc::new(shape), std::any::type_name::<T>())
     }
 
+    /// Name of the type of the stored value
+    #[must_use]
+    pub fn type_name(&self) -> &'static str {
+        self.1
+    }
+
     /// Check if the stored value is of type `T`, and give a reference to it
     /// if the type matches.
     /// Will return [`None`] if the type of the stored value does not match `T`.
@@ -129,6 +185,8 @@ impl core::fmt::Debug for CustomCollisionShape {
 /// If there isn't any [`RigidBody`] in the entity,
 /// the collision shape will be attached to the [`RigidBody`] of the parent entity.
 ///
+/// The shape is scaled by the scale of the entity `GlobalTransform` (see [`CollisionShape::scaled`]).
+///
 /// # Example
 ///
 /// ```
@@ -222,6 +280,66 @@ pub enum CollisionShape {
         radius: f32,
     },
 
+    /// A segment between two points
+    ///
+    /// In 2d the `z` axis is ignored
+    Segment {
+        /// First point of the segment
+        a: Vec3,
+        /// Second point of the segment
+        b: Vec3,
+    },
+
+    /// A triangle defined by its three vertices
+    ///
+    /// In 2d the `z` axis is ignored
+    Triangle {
+        /// First vertex of the triangle
+        a: Vec3,
+        /// Second vertex of the triangle
+        b: Vec3,
+        /// Third vertex of the triangle
+        c: Vec3,
+    },
+
+    /// A set of connected segments
+    ///
+    /// In 2d the `z` axis is ignored
+    Polyline {
+        /// The vertices of the polyline
+        vertices: Vec<Vec3>,
+        /// The pairs of vertex indices forming each segment
+        ///
+        /// If `None`, the vertices are connected in order (`0-1`, `1-2`, etc.)
+        ///
+        /// The shape is invalid if a segment refers to a vertex that doesn't exist.
+        indices: Option<Vec<[u32; 2]>>,
+    },
+
+    /// A triangle mesh, that can be concave
+    ///
+    /// This shape is usefull for static level geometry. It has no volume, and therefore no mass.
+    ///
+    /// In 2d the `z` axis is ignored
+    TriMesh {
+        /// The vertices of the mesh
+        vertices: Vec<Vec3>,
+        /// The triplets of vertex indices forming each triangle
+        ///
+        /// The shape is invalid if a triangle refers to a vertex that doesn't exist.
+        indices: Vec<[u32; 3]>,
+    },
+
+    /// A shape made of multiple shapes, each with its own position relative to the entity
+    ///
+    /// The children cannot be themselves composite shapes ([`TriMesh`](CollisionShape::TriMesh),
+    /// [`Polyline`](CollisionShape::Polyline), [`HeightField`](CollisionShape::HeightField) or
+    /// [`Compound`](CollisionShape::Compound)). Such children make the shape invalid.
+    Compound {
+        /// The shapes composing the compound shape
+        shapes: Vec<CompoundShapeChild>,
+    },
+
     /// A Custom shape, the actual shape is abstracted, and will be determined
     /// by a corresponding backend depending on the implementation details
     ///
@@ -238,6 +356,161 @@ impl Default for CollisionShape {
     }
 }
 
+impl CollisionShape {
+    /// Returns true if the shape is made of multiple shapes, and therefore cannot be a child of a
+    /// [`CollisionShape::Compound`]
+    #[must_use]
+    pub fn is_composite(&self) -> bool {
+        matches!(
+            self,
+            Self::HeightField { .. }
+                | Self::Polyline { .. }
+                | Self::TriMesh { .. }
+                | Self::Compound { .. }
+        )
+    }
+
+    /// Returns the shape scaled by the given factors
+    ///
+    /// The points of the shapes defined by their vertices are scaled as-is, so that they follow
+    /// any (non-uniform and even negative) scale. The dimensions of the other shapes are scaled
+    /// by the absolute factors along their axes. The radius of the round shapes
+    /// (spheres, capsules, cones, cylinders and rounded borders) cannot be stretched, and is
+    /// scaled by the largest factor of its axes instead.
+    ///
+    /// The children of a [`Compound`](Self::Compound) shape are scaled along the axes of the
+    /// entity, which is only exact for a uniform scale or for children that aren't rotated.
+    ///
+    /// In 2d the `z` factor is ignored.
+    ///
+    /// The [`Custom`](Self::Custom) shapes cannot be scaled, and are returned unchanged.
+    #[must_use]
+    pub fn scaled(&self, scale: Vec3) -> Self {
+        #[cfg(not(dim3))]
+        let scale = scale.truncate().extend(0.0);
+
+        let abs = scale.abs();
+        let radius_factor = abs.max_element();
+
+        match self {
+            Self::Sphere { radius } => Self::Sphere {
+                radius: radius * radius_factor,
+            },
+            Self::Capsule {
+                half_segment,
+                radius,
+            } => Self::Capsule {
+                half_segment: half_segment * abs.y,
+                radius: radius * abs.x.max(abs.z),
+            },
+            Self::Cuboid {
+                half_extends,
+                border_radius,
+            } => Self::Cuboid {
+                half_extends: *half_extends * abs,
+                border_radius: border_radius.map(|radius| radius * radius_factor),
+            },
+            Self::ConvexHull {
+                points,
+                border_radius,
+            } => Self::ConvexHull {
+                points: points.iter().map(|point| *point * scale).collect(),
+                border_radius: border_radius.map(|radius| radius * radius_factor),
+            },
+            Self::HeightField { size, heights } => Self::HeightField {
+                size: *size * Vec2::new(abs.x, abs.z),
+                heights: heights
+                    .iter()
+                    .map(|row| row.iter().map(|height| height * scale.y).collect())
+                    .collect(),
+            },
+            #[cfg(dim3)]
+            Self::Cone {
+                half_height,
+                radius,
+            } => Self::Cone {
+                half_height: half_height * abs.y,
+                radius: radius * abs.x.max(abs.z),
+            },
+            #[cfg(dim3)]
+            Self::Cylinder {
+                half_height,
+                radius,
+            } => Self::Cylinder {
+                half_height: half_height * abs.y,
+                radius: radius * abs.x.max(abs.z),
+            },
+            Self::Segment { a, b } => Self::Segment {
+                a: *a * scale,
+                b: *b * scale,
+            },
+            Self::Triangle { a, b, c } => Self::Triangle {
+                a: *a * scale,
+                b: *b * scale,
+                c: *c * scale,
+            },
+            Self::Polyline { vertices, indices } => Self::Polyline {
+                vertices: vertices.iter().map(|vertex| *vertex * scale).collect(),
+                indices: indices.clone(),
+            },
+            Self::TriMesh { vertices, indices } => Self::TriMesh {
+                vertices: vertices.iter().map(|vertex| *vertex * scale).collect(),
+                indices: indices.clone(),
+            },
+            Self::Compound { shapes } => Self::Compound {
+                shapes: shapes
+                    .iter()
+                    .map(|child| CompoundShapeChild {
+                        translation: child.translation * scale,
+                        rotation: child.rotation,
+                        shape: child.shape.scaled(scale),
+                    })
+                    .collect(),
+            },
+            Self::Custom { .. } => self.clone(),
+        }
+    }
+}
+
+/// A shape of a [`CollisionShape::Compound`], with its position relative to the entity
+#[derive(Debug, Clone, Reflect)]
+pub struct CompoundShapeChild {
+    /// Translation of the shape, relative to the entity
+    pub translation: Vec3,
+
+    /// Rotation of the shape, relative to the entity
+    pub rotation: Quat,
+
+    /// The shape
+    pub shape: CollisionShape,
+}
+
+impl CompoundShapeChild {
+    /// Create a child shape positioned at the origin of the entity
+    #[must_use]
+    pub fn new(shape: CollisionShape) -> Self {
+        Self {
+            translation: Vec3::ZERO,
+            rotation: Quat::IDENTITY,
+            shape,
+        }
+    }
+
+    /// Returns a new version with the given translation
+    #[must_use]
+    pub fn with_translation(mut self, translation: Vec3) -> Self {
+        self.translation = translation;
+        self
+    }
+
+    /// Returns a new version with the given rotation
+    #[must_use]
+    pub fn with_rotation(mut self, rotation: Quat) -> Self {
+        self.rotation = rotation;
+        self
+    }
+}
+
 /// Component that mark the entity as being a rigid body
 ///
 /// It'll need some [`CollisionShape`] to be attached. Either in the same entity or in a direct child
@@ -348,9 +621,39 @@ impl RigidBody {
 #[derive(Debug, Component, Copy, Clone, Default, Reflect)]
 pub struct SensorShape;
 
+/// Enable continuous collision detection (CCD) for the [`RigidBody`] of the same entity
+///
+/// Without CCD, a fast body may move through a thin obstacle in a single physics step, without
+/// ever being in contact with it (a.k.a. *tunneling*). With CCD, the motion of the body is swept
+/// to detect such collisions, which makes it more expensive to simulate.
+///
+/// It is typically useful for bullets and other small and fast bodies.
+///
+/// The component can be inserted or removed at any time to enable or disable CCD.
+///
+/// # Example
+///
+/// ```
+/// # use heron_core::*;
+/// # use bevy::prelude::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 0.1 })
+///         .insert(Velocity::from_linear(Vec3::X * 500.0))
+///         .insert(ContinuousCollisionDetection);
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, Default, Reflect)]
+pub struct ContinuousCollisionDetection;
+
 /// Component that defines the physics properties of the rigid body
 ///
-/// It must be inserted on the same entity of a [`RigidBody`]
+/// It can be inserted on the same entity of a [`RigidBody`], in which case it applies to all its
+/// collision shapes. It can also be inserted on a child entity holding a [`CollisionShape`], to
+/// override the material of the rigid body for that shape only.
+///
+/// Changing the material updates the colliders in place, without re-creating the rigid body.
 ///
 /// # Example
 ///
@@ -364,6 +667,8 @@ pub struct SensorShape;
 ///             restitution: 0.5, // Define the restitution. Higher value means more "bouncy"
 ///             density: 2.0, // Define the density. Higher value means heavier.
 ///             friction: 0.5, // Define the friction. Higher value means higher friction.
+///             friction_combine_rule: CombineRule::Min, // Use the lowest friction of the two bodies in contact
+///             ..Default::default()
 ///         });
 /// }
 /// ```
@@ -389,6 +694,12 @@ pub struct PhysicMaterial {
     ///
     /// Typical values are between 0 (ideal) and 1 (max friction)
     pub friction: f32,
+
+    /// How the restitution coefficients of two colliders in contact are combined
+    pub restitution_combine_rule: CombineRule,
+
+    /// How the friction coefficients of two colliders in contact are combined
+    pub friction_combine_rule: CombineRule,
 }
 
 impl PhysicMaterial {
@@ -405,6 +716,36 @@ impl Default for PhysicMaterial {
             restitution: Self::PERFECTLY_INELASTIC_RESTITUTION,
             density: 1.0,
             friction: 0.0,
+            restitution_combine_rule: CombineRule::default(),
+            friction_combine_rule: CombineRule::default(),
         }
     }
 }
+
+/// Rule defining how the coefficients (friction or restitution) of two colliders in contact are
+/// combined
+///
+/// When the two colliders use different rules, the one declared last in this enum is used. (For
+/// instance, [`Max`](Self::Max) takes precedence over all the other rules.)
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Reflect)]
+pub enum CombineRule {
+    /// The average of the two coefficients
+    ///
+    /// It is the default rule.
+    Average,
+
+    /// The smallest of the two coefficients
+    Min,
+
+    /// The product of the two coefficients
+    Multiply,
+
+    /// The largest of the two coefficients
+    Max,
+}
+
+impl Default for CombineRule {
+    fn default() -> Self {
+        Self::Average
+    }
+}
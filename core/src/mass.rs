@@ -0,0 +1,160 @@
+use bevy::ecs::component::Component;
+use bevy::math::Vec3;
+use bevy::reflect::Reflect;
+
+/// Component that defines the mass properties of a rigid body explicitly
+///
+/// It must be inserted on the same entity of a [`RigidBody`](crate::RigidBody).
+///
+/// By default, the mass of a rigid body is computed from the [`PhysicMaterial::density`](crate::PhysicMaterial::density)
+/// and the volume of its collision shapes. When this component is present, the density of the
+/// collision shapes is ignored, and the rigid body uses the given mass properties instead.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(MassProperties::from_mass(10.0).with_local_center_of_mass(Vec3::Y * -0.5));
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect)]
+pub struct MassProperties {
+    /// Total mass of the rigid body
+    pub mass: f32,
+
+    /// Center of mass, in the local space of the rigid body
+    pub local_center_of_mass: Vec3,
+
+    /// Angular inertia along the principal axes (the local axes of the rigid body)
+    ///
+    /// In 2d, only the `z` component is used.
+    pub principal_inertia: Vec3,
+}
+
+impl Default for MassProperties {
+    fn default() -> Self {
+        Self::from_mass(1.0)
+    }
+}
+
+impl MassProperties {
+    /// Returns mass properties with the given mass, centered at the origin of the rigid body,
+    /// and with the angular inertia of a unit sphere of that mass
+    #[must_use]
+    pub fn from_mass(mass: f32) -> Self {
+        Self {
+            mass,
+            local_center_of_mass: Vec3::ZERO,
+            principal_inertia: Vec3::splat(mass * 0.4),
+        }
+    }
+
+    /// Returns a new version with the given center of mass (in the local space of the rigid body)
+    #[must_use]
+    pub fn with_local_center_of_mass(mut self, local_center_of_mass: Vec3) -> Self {
+        self.local_center_of_mass = local_center_of_mass;
+        self
+    }
+
+    /// Returns a new version with the given principal angular inertia
+    #[must_use]
+    pub fn with_principal_inertia(mut self, principal_inertia: Vec3) -> Self {
+        self.principal_inertia = principal_inertia;
+        self
+    }
+}
+
+/// Component that reflects the mass properties computed by the physics engine
+///
+/// It is not inserted automatically. Add `ComputedMassProperties::default()` to a
+/// [`RigidBody`](crate::RigidBody) entity, and heron keeps it up-to-date after each physics step.
+///
+/// Changing it has no effect on the simulation. Use [`MassProperties`] to define the mass
+/// explicitly.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn push(mut query: Query<(&ComputedMassProperties, &mut Acceleration)>) {
+///     for (mass, mut acceleration) in query.iter_mut() {
+///         // Apply the same force to all bodies, whatever their mass
+///         acceleration.linear = Vec3::X * 100.0 * mass.inverse_mass();
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Default, Reflect)]
+pub struct ComputedMassProperties {
+    mass: f32,
+    local_center_of_mass: Vec3,
+    principal_inertia: Vec3,
+}
+
+impl ComputedMassProperties {
+    /// Create the computed mass properties
+    ///
+    /// This is called by the physics backend and should not be used by games
+    #[doc(hidden)]
+    #[must_use]
+    pub fn new(mass: f32, local_center_of_mass: Vec3, principal_inertia: Vec3) -> Self {
+        Self {
+            mass,
+            local_center_of_mass,
+            principal_inertia,
+        }
+    }
+
+    /// Total mass of the rigid body
+    ///
+    /// It is zero for bodies that have an infinite mass (e.g. static bodies)
+    #[must_use]
+    pub fn mass(&self) -> f32 {
+        self.mass
+    }
+
+    /// Inverse of the total mass, or zero if the mass is zero
+    #[must_use]
+    pub fn inverse_mass(&self) -> f32 {
+        if self.mass > 0.0 {
+            1.0 / self.mass
+        } else {
+            0.0
+        }
+    }
+
+    /// Center of mass, in the local space of the rigid body
+    #[must_use]
+    pub fn local_center_of_mass(&self) -> Vec3 {
+        self.local_center_of_mass
+    }
+
+    /// Angular inertia along the principal axes
+    ///
+    /// In 2d, only the `z` component is meaningful.
+    #[must_use]
+    pub fn principal_inertia(&self) -> Vec3 {
+        self.principal_inertia
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverse_mass_of_zero_mass_is_zero() {
+        assert_eq!(ComputedMassProperties::default().inverse_mass(), 0.0);
+    }
+
+    #[test]
+    fn inverse_mass() {
+        let mass = ComputedMassProperties::new(4.0, Vec3::ZERO, Vec3::ONE);
+        assert!((mass.inverse_mass() - 0.25).abs() < f32::EPSILON);
+    }
+}
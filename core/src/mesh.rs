@@ -0,0 +1,96 @@
+use bevy::asset::Handle;
+use bevy::ecs::component::Component;
+use bevy::reflect::Reflect;
+use bevy::render::mesh::Mesh;
+
+/// Component that generates the [`CollisionShape`](crate::CollisionShape) of its entity from a
+/// bevy [`Mesh`] asset
+///
+/// Once the mesh is loaded, the collision shape is built and inserted in the entity. It is rebuilt
+/// whenever the mesh asset is modified (e.g. when hot-reloaded) or when this component is changed.
+///
+/// The mesh must use the `TriangleList` primitive topology, except for
+/// [`MeshShapeKind::ConvexHull`] that only needs the vertex positions.
+///
+/// This component requires the `collision-from-mesh` feature, as well as the mesh assets to be
+/// registered in the app (which is done by the bevy render plugin).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Static)
+///         .insert(MeshCollisionShape::trimesh(asset_server.load("level.gltf#Mesh0/Primitive0")));
+/// }
+/// ```
+#[derive(Debug, Component, Clone, PartialEq, Reflect)]
+pub struct MeshCollisionShape {
+    /// Mesh from which the collision shape is generated
+    pub mesh: Handle<Mesh>,
+
+    /// Kind of collision shape to generate
+    pub kind: MeshShapeKind,
+}
+
+/// Kind of collision shape generated by a [`MeshCollisionShape`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Reflect)]
+pub enum MeshShapeKind {
+    /// A [`CollisionShape::ConvexHull`](crate::CollisionShape::ConvexHull) of the mesh vertices
+    ConvexHull,
+
+    /// A [`CollisionShape::TriMesh`](crate::CollisionShape::TriMesh) with the exact triangles of
+    /// the mesh
+    ///
+    /// This is the most precise shape, but it has no volume. It is best suited for static level
+    /// geometry.
+    TriMesh,
+
+    /// A [`CollisionShape::Compound`](crate::CollisionShape::Compound) of convex hulls,
+    /// approximating the mesh with an approximate convex decomposition
+    ///
+    /// Unlike [`TriMesh`](Self::TriMesh), the generated shape has a volume and can be used by
+    /// dynamic bodies, even when the mesh is concave. The decomposition is costly to compute.
+    ///
+    /// This shape is exclusive to the 3d API, you must enable the "3d" flag to use it.
+    #[cfg(dim3)]
+    ConvexDecomposition,
+}
+
+impl Default for MeshShapeKind {
+    fn default() -> Self {
+        Self::ConvexHull
+    }
+}
+
+impl MeshCollisionShape {
+    /// Generate a convex hull of the mesh vertices
+    #[must_use]
+    pub fn convex_hull(mesh: Handle<Mesh>) -> Self {
+        Self {
+            mesh,
+            kind: MeshShapeKind::ConvexHull,
+        }
+    }
+
+    /// Generate a triangle mesh with the exact triangles of the mesh
+    #[must_use]
+    pub fn trimesh(mesh: Handle<Mesh>) -> Self {
+        Self {
+            mesh,
+            kind: MeshShapeKind::TriMesh,
+        }
+    }
+
+    /// Generate a compound of convex hulls approximating the mesh
+    #[must_use]
+    #[cfg(dim3)]
+    pub fn convex_decomposition(mesh: Handle<Mesh>) -> Self {
+        Self {
+            mesh,
+            kind: MeshShapeKind::ConvexDecomposition,
+        }
+    }
+}
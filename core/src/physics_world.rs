@@ -0,0 +1,38 @@
+use bevy::ecs::component::Component;
+use bevy::reflect::Reflect;
+
+/// Component that assigns a rigid body to a physics world
+///
+/// The physics worlds are simulated independently of each other: the bodies of different worlds
+/// never collide, and each world has its own [`Gravity`](crate::Gravity),
+/// [`PhysicsSteps`](crate::PhysicsSteps) and [`PhysicsTime`](crate::PhysicsTime).
+///
+/// It must be inserted on the same entity of a [`RigidBody`](crate::RigidBody), before the body
+/// is created. Changing it afterward is not supported: the body stays in its former world, and a
+/// warning is logged. The collision shapes and joints belong to the world of their rigid bodies.
+///
+/// The bodies without this component belong to the default world ([`PhysicsWorldId::DEFAULT`]),
+/// which is configured by the app resources. Other worlds must be registered in the physics
+/// backend before being used. Otherwise their bodies aren't simulated.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// const PREVIEW_WORLD: PhysicsWorldId = PhysicsWorldId(1);
+///
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(PREVIEW_WORLD); // Simulated independently of the default world
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, Default, Eq, PartialEq, Hash, Reflect)]
+pub struct PhysicsWorldId(pub u32);
+
+impl PhysicsWorldId {
+    /// Id of the default physics world, to which belong the bodies without [`PhysicsWorldId`]
+    pub const DEFAULT: Self = Self(0);
+}
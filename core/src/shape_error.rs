@@ -0,0 +1,90 @@
+use core::fmt::{self, Display, Formatter};
+
+use bevy::ecs::component::Component;
+
+/// Reason why a [`CollisionShape`](crate::CollisionShape) could not be built by the physics backend
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CollisionShapeError {
+    /// A dimension of the shape (radius, half extent, half height, etc.) is negative, infinite or
+    /// NaN
+    InvalidDimension,
+
+    /// The convex hull could not be computed from the points
+    ///
+    /// This happens when there aren't enough points, or when they are all aligned (or coplanar in
+    /// 3d).
+    InvalidConvexHull,
+
+    /// The height field doesn't have at least two points on each axis, its rows don't all have
+    /// the same length, or some of its heights aren't finite
+    InvalidHeightField,
+
+    /// The polyline, triangle mesh or compound shape doesn't have any element
+    EmptyShape,
+
+    /// A segment of the polyline or a triangle of the mesh refers to a vertex that doesn't exist
+    IndexOutOfBounds,
+
+    /// A child of the compound shape is itself a composite shape (see
+    /// [`CollisionShape::is_composite`](crate::CollisionShape::is_composite))
+    NestedCompound,
+
+    /// A vertex of the segment, triangle, polyline or triangle mesh is infinite or NaN
+    NonFiniteVertex,
+
+    /// The [`CustomCollisionShape`](crate::CustomCollisionShape) is not supported by the backend
+    ///
+    /// Contains the type name of the custom shape.
+    UnsupportedCustomShape(&'static str),
+
+    /// The shape is not supported by the backend
+    UnsupportedShape,
+}
+
+impl Display for CollisionShapeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDimension => write!(f, "a dimension is negative or not finite"),
+            Self::InvalidConvexHull => write!(f, "the convex hull cannot be computed"),
+            Self::InvalidHeightField => write!(f, "the height field is degenerate"),
+            Self::EmptyShape => write!(f, "the shape has no element"),
+            Self::IndexOutOfBounds => write!(f, "an index refers to a vertex that doesn't exist"),
+            Self::NestedCompound => write!(f, "a child of the compound shape is composite"),
+            Self::NonFiniteVertex => write!(f, "a vertex is not finite"),
+            Self::UnsupportedCustomShape(type_name) => {
+                write!(f, "the custom shape type {} is not supported", type_name)
+            }
+            Self::UnsupportedShape => write!(f, "the shape is not supported"),
+        }
+    }
+}
+
+impl std::error::Error for CollisionShapeError {}
+
+/// Component inserted by heron on the entities whose [`CollisionShape`](crate::CollisionShape)
+/// could not be built
+///
+/// No collider is created for such entities, but the rest of the world keeps being simulated.
+/// The component is removed when the collision shape is changed or removed, and heron then tries
+/// to build the new shape.
+///
+/// A [`CollisionShapeErrorEvent`](crate::CollisionShapeErrorEvent) is also fired when it is
+/// inserted.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn fix_invalid_shapes(mut query: Query<&mut CollisionShape, With<InvalidCollisionShape>>) {
+///     for mut shape in query.iter_mut() {
+///         *shape = CollisionShape::Sphere { radius: 1.0 };
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Clone, Eq, PartialEq)]
+pub struct InvalidCollisionShape {
+    /// Reason why the collision shape could not be built
+    pub error: CollisionShapeError,
+}
@@ -0,0 +1,102 @@
+use bevy::ecs::component::Component;
+use bevy::reflect::Reflect;
+
+/// Component that reflects and controls the sleep state of a rigid body
+///
+/// It must be inserted on the same entity of a [`RigidBody`](crate::RigidBody).
+///
+/// A body falls asleep once its pseudo-kinetic energy (the squared norm of its linear and angular
+/// velocities) stays below the [`threshold`](Self::threshold) for a while. A sleeping body isn't
+/// simulated until something wakes it up (e.g. a collision, a change of velocity, etc.).
+///
+/// The [`sleeping`](Self::sleeping) flag is kept up-to-date after each physics step. It can also
+/// be changed to force the body to sleep or to wake up.
+///
+/// A negative threshold prevents the body to fall asleep, even when it is forced to sleep.
+/// See [`Sleeping::disabled`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(Sleeping::default());
+/// }
+///
+/// fn wake_up_all(mut query: Query<&mut Sleeping>) {
+///     for mut sleeping in query.iter_mut() {
+///         sleeping.sleeping = false;
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Reflect)]
+pub struct Sleeping {
+    /// Pseudo-kinetic energy below which the body may fall asleep
+    pub threshold: f32,
+
+    /// Whether the body is currently sleeping
+    pub sleeping: bool,
+}
+
+impl Default for Sleeping {
+    fn default() -> Self {
+        Self {
+            threshold: 0.01,
+            sleeping: false,
+        }
+    }
+}
+
+impl Sleeping {
+    /// Prevent the body to ever fall asleep
+    #[must_use]
+    pub fn disabled() -> Self {
+        Self {
+            threshold: -1.0,
+            sleeping: false,
+        }
+    }
+
+    /// Start the body asleep
+    ///
+    /// The body is put to sleep right after its first physics step.
+    #[must_use]
+    pub fn asleep() -> Self {
+        Self {
+            sleeping: true,
+            ..Self::default()
+        }
+    }
+
+    /// Returns a new version with the given threshold
+    #[must_use]
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Returns true if the body can fall asleep
+    #[must_use]
+    pub fn can_sleep(&self) -> bool {
+        self.threshold >= 0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_sleep_by_default() {
+        assert!(Sleeping::default().can_sleep());
+    }
+
+    #[test]
+    fn cannot_sleep_when_disabled() {
+        assert!(!Sleeping::disabled().can_sleep());
+    }
+}
@@ -10,8 +10,10 @@ use bevy::prelude::*;
 /// This resource is used to tune the precision and performance of the physics system.
 /// It doesn't change the speed of the simulation.
 /// To change the time scale, look at the [`PhysicsTime`](crate::PhysicsTime) resource instead.
+#[derive(Debug, Clone)]
 pub struct PhysicsSteps(Mode);
 
+#[derive(Debug, Clone)]
 enum Mode {
     MaxDeltaTime(Duration),
     EveryFrame(Duration),
@@ -142,6 +144,18 @@ impl PhysicsSteps {
         }
     }
 
+    /// Returns how far the time has progressed toward the next physics step, between `0.0` and `1.0`
+    ///
+    /// Returns `None` if the physics steps are not run at a fixed rate (that is, if a step is
+    /// performed every frame).
+    #[must_use]
+    pub fn step_progress(&self) -> Option<f32> {
+        match &self.0 {
+            Mode::EveryFrame(_) | Mode::MaxDeltaTime(_) => None,
+            Mode::Timer(timer) => Some(timer.percent()),
+        }
+    }
+
     /// Time that elapses between each physics step
     #[must_use]
     pub fn duration(&self) -> PhysicsStepDuration {
@@ -152,12 +166,19 @@ impl PhysicsSteps {
         }
     }
 
-    pub(crate) fn update(mut physics_steps: ResMut<'_, PhysicsSteps>, time: Res<'_, Time>) {
-        physics_steps.do_update(time.delta());
+    /// System that advances the time toward the next physics step
+    ///
+    /// It is added to the app by the [`CorePlugin`](crate::CorePlugin).
+    pub fn update(mut physics_steps: ResMut<'_, PhysicsSteps>, time: Res<'_, Time>) {
+        physics_steps.tick(time.delta());
     }
 
+    /// Advances the time toward the next physics step by the given duration
+    ///
+    /// It is called every frame with the delta time of the frame (see [`PhysicsSteps::update`]).
+    /// Calling it manually is only useful to control the time precisely, e.g. in tests.
     #[inline]
-    fn do_update(&mut self, delta: Duration) {
+    pub fn tick(&mut self, delta: Duration) {
         if let Mode::Timer(timer) = &mut self.0 {
             timer.tick(delta);
         }
@@ -178,7 +199,7 @@ mod tests {
         #[case] mut steps: PhysicsSteps,
         #[case] delta_time: f32,
     ) {
-        steps.do_update(Duration::from_secs_f32(delta_time));
+        steps.tick(Duration::from_secs_f32(delta_time));
         assert!(!steps.is_step_frame());
     }
 
@@ -192,7 +213,21 @@ mod tests {
         #[case] mut steps: PhysicsSteps,
         #[case] delta_time: f32,
     ) {
-        steps.do_update(Duration::from_secs_f32(delta_time));
+        steps.tick(Duration::from_secs_f32(delta_time));
         assert!(steps.is_step_frame());
     }
+
+    #[test]
+    fn step_progress_is_the_fraction_of_elapsed_time() {
+        let mut steps = PhysicsSteps::from_delta_time(Duration::from_secs(1));
+        steps.tick(Duration::from_secs_f32(1.25));
+        let progress = steps.step_progress().unwrap();
+        assert!((progress - 0.25).abs() < 0.001);
+    }
+
+    #[test]
+    fn no_step_progress_when_stepping_every_frame() {
+        let steps = PhysicsSteps::every_frame(Duration::from_secs(1));
+        assert!(steps.step_progress().is_none());
+    }
 }
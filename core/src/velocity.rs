@@ -71,6 +71,44 @@ pub struct Acceleration {
     pub angular: AxisAngle,
 }
 
+/// Component that applies a linear and angular impulse to the rigid body, **once**.
+///
+/// It must be inserted on the same entity of a [`RigidBody`](crate::RigidBody)
+///
+/// The impulse is applied at the next physics step, after which the component is removed. This makes
+/// it well suited for instantaneous changes of velocity, like jumps, knockbacks and explosions.
+/// (For continuous forces, look at [`Acceleration`] instead)
+///
+/// The linear part is in mass times "unit" per second on each axis, represented as a `Vec3`.
+/// The angular part is in inertia times radians per second around an axis, represented as an [`AxisAngle`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+///
+/// fn jump(mut commands: Commands, player: Query<Entity, With<RigidBody>>) {
+///     for entity in player.iter() {
+///         commands.entity(entity).insert(Impulse::from_linear(Vec3::Y * 10.0));
+///     }
+/// }
+/// ```
+#[derive(Debug, Component, Copy, Clone, PartialEq, Default, Reflect)]
+pub struct Impulse {
+    /// Linear impulse, in mass times units-per-second on each axis
+    pub linear: Vec3,
+
+    /// Angular impulse, in inertia times radians-per-second around an axis
+    pub angular: AxisAngle,
+
+    /// World-space point at which the linear impulse is applied
+    ///
+    /// When `None`, the linear impulse is applied at the center of mass, and doesn't create any
+    /// angular impulse.
+    pub point: Option<Vec3>,
+}
+
 /// Component that defines the linear and angular damping.
 ///
 /// It must be inserted on the same entity of a [`RigidBody`](crate::RigidBody)
@@ -176,6 +214,58 @@ impl Acceleration {
     }
 }
 
+impl Impulse {
+    /// Returns a linear impulse from a vector
+    #[must_use]
+    pub fn from_linear(linear: Vec3) -> Self {
+        Self {
+            linear,
+            ..Self::default()
+        }
+    }
+
+    /// Returns an angular impulse from an [`AxisAngle`]
+    #[must_use]
+    pub fn from_angular(angular: AxisAngle) -> Self {
+        Self {
+            angular,
+            ..Self::default()
+        }
+    }
+
+    /// Returns a linear impulse applied at a world-space point
+    ///
+    /// If the point isn't the center of mass, it'll also make the body spin.
+    #[must_use]
+    pub fn at_point(linear: Vec3, point: Vec3) -> Self {
+        Self {
+            linear,
+            point: Some(point),
+            ..Self::default()
+        }
+    }
+
+    /// Returns a new version with the given linear impulse
+    #[must_use]
+    pub fn with_linear(mut self, linear: Vec3) -> Self {
+        self.linear = linear;
+        self
+    }
+
+    /// Returns a new version with the given angular impulse
+    #[must_use]
+    pub fn with_angular(mut self, angular: AxisAngle) -> Self {
+        self.angular = angular;
+        self
+    }
+}
+
+impl NearZero for Impulse {
+    fn is_near_zero(self) -> bool {
+        self.linear.is_near_zero() && self.angular.is_near_zero()
+    }
+}
+
 impl Damping {
     /// Returns a linear damping
     #[must_use]
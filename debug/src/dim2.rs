@@ -1,19 +1,25 @@
 use bevy::prelude::*;
-use bevy_prototype_lyon::{entity::ShapeBundle, prelude::*};
+use bevy_prototype_lyon::{
+    entity::{Path, ShapeBundle},
+    prelude::*,
+};
 use lyon_path::{
     builder::BorderRadii,
-    math::{Point, Rect, Size},
+    math::{Angle, Point, Rect, Size, Transform as Transform2D, Vector},
     path::Builder,
     traits::PathBuilder,
+    PathEvent,
 };
 
 use heron_core::{CollisionShape, RigidBody, SensorShape};
 use heron_rapier::{
-    convert::{IntoBevy, IntoRapier},
+    convert::IntoBevy,
     rapier2d::geometry::{ColliderSet, Shape},
-    ColliderHandle,
+    ColliderHandle, ColliderHandleMap,
 };
 
+use crate::overlays::OverlaySegments;
+
 #[allow(clippy::wildcard_imports)]
 use super::*;
 
@@ -24,16 +30,18 @@ pub(crate) fn systems() -> SystemSet {
         .with_system(create_debug_sprites.system())
 }
 
+/// Only the shapes of the default physics world are drawn, as the colliders of the additional
+/// worlds are not in the `ColliderSet` resource
 fn create_debug_sprites(
     mut commands: Commands<'_, '_>,
     colliders: Res<'_, ColliderSet>,
+    handles: Res<'_, ColliderHandleMap>,
     query: Query<
         '_,
         '_,
         (
             Entity,
             &CollisionShape,
-            &ColliderHandle,
             &GlobalTransform,
             Option<&RigidBody>,
             Option<&SensorShape>,
@@ -42,8 +50,11 @@ fn create_debug_sprites(
     >,
     debug_color: Res<'_, DebugColor>,
 ) {
-    for (entity, body, handle, transform, rigid_body_option, sensor_option) in query.iter() {
-        if let Some(collider) = colliders.get(handle.into_rapier()) {
+    for (entity, body, transform, rigid_body_option, sensor_option) in query.iter() {
+        if let Some(collider) = handles
+            .get(&entity)
+            .and_then(|handle| colliders.get(*handle))
+        {
             commands
                 .entity(entity)
                 .with_children(|builder| {
@@ -66,6 +77,7 @@ fn replace_debug_sprite(
     mut commands: Commands<'_, '_>,
     mut map: ResMut<'_, DebugEntityMap>,
     colliders: Res<'_, ColliderSet>,
+    handles: Res<'_, ColliderHandleMap>,
     debug_color: Res<'_, DebugColor>,
     query: Query<
         '_,
@@ -73,19 +85,21 @@ fn replace_debug_sprite(
         (
             Entity,
             &CollisionShape,
-            &ColliderHandle,
             &GlobalTransform,
             Option<&RigidBody>,
             Option<&SensorShape>,
         ),
-        (With<HasDebug>, Changed<CollisionShape>),
+        (
+            With<HasDebug>,
+            Or<(Changed<CollisionShape>, Changed<ColliderHandle>)>,
+        ),
     >,
 ) {
-    for (parent_entity, body, handle, transform, rigid_body_option, sensor_option) in query.iter() {
-        if let (Some(debug_entity), Some(collider)) = (
-            map.remove(&parent_entity),
-            colliders.get(handle.into_rapier()),
-        ) {
+    for (parent_entity, body, transform, rigid_body_option, sensor_option) in query.iter() {
+        let collider = handles
+            .get(&parent_entity)
+            .and_then(|handle| colliders.get(*handle));
+        if let (Some(debug_entity), Some(collider)) = (map.remove(&parent_entity), collider) {
             commands.entity(debug_entity).despawn();
             commands.entity(parent_entity).with_children(|builder| {
                 builder
@@ -113,13 +127,19 @@ fn delete_debug_sprite(
     }
 }
 
+/// The collider is built with the scale of the entity, so is the debug shape. The scale is
+/// therefore removed from the debug entity itself.
 fn create_shape(
     body: &CollisionShape,
     shape: &dyn Shape,
     color: Color,
     transform: GlobalTransform,
 ) -> ShapeBundle {
-    base_builder(body, shape).build(
+    GeometryBuilder::build_as(
+        &ShapeGeometry {
+            body: &body.scaled(transform.scale),
+            shape,
+        },
         DrawMode::Fill(FillMode {
             color,
             options: FillOptions::default(),
@@ -132,122 +152,321 @@ fn create_shape(
     )
 }
 
-#[allow(clippy::too_many_lines)]
-fn base_builder(body: &CollisionShape, shape: &dyn Shape) -> GeometryBuilder {
-    let mut builder = GeometryBuilder::new();
+/// Width of the shapes that have no area (segments, polylines, etc.)
+const LINE_WIDTH: f32 = 2.0;
 
-    match body {
-        CollisionShape::Sphere { radius } => {
-            builder = builder.add(&shapes::Circle {
-                radius: *radius,
-                center: Vec2::ZERO,
-            });
-        }
-        CollisionShape::Capsule {
-            half_segment,
-            radius,
-        } => {
-            builder = builder.add(&Capsule {
-                half_segment: *half_segment,
-                radius: *radius,
-            });
+/// Depth of the overlays, so that they are drawn on top of the collision shapes
+const OVERLAY_Z: f32 = 10.0;
+
+/// Entity drawing the overlay segments of one color
+#[derive(Component)]
+pub(crate) struct IsOverlay {
+    color: Color,
+    is_empty: bool,
+}
+
+/// The overlays change every frame. One entity is kept per color, and only its path is updated.
+pub(crate) fn draw_overlays(
+    mut commands: Commands<'_, '_>,
+    segments: Res<'_, OverlaySegments>,
+    mut overlays: Query<'_, '_, (&mut IsOverlay, &mut Path)>,
+) {
+    let mut by_color: Vec<(Color, Segments)> = Vec::new();
+    for (start, end, color) in &segments.0 {
+        let segment = (start.truncate(), end.truncate());
+        match by_color.iter_mut().find(|(c, _)| c == color) {
+            Some((_, segments)) => segments.0.push(segment),
+            None => by_color.push((*color, Segments(vec![segment]))),
         }
-        CollisionShape::Cuboid {
-            half_extends,
-            border_radius,
-        } => {
-            struct RoundedRectangle {
-                width: f32,
-                height: f32,
-                radius: f32,
+    }
+
+    for (mut overlay, mut path) in overlays.iter_mut() {
+        match by_color
+            .iter()
+            .position(|(color, _)| *color == overlay.color)
+        {
+            Some(index) => {
+                *path = ShapePath::build_as(&by_color.swap_remove(index).1);
+                overlay.is_empty = false;
             }
-            impl Geometry for RoundedRectangle {
-                fn add_geometry(&self, b: &mut Builder) {
-                    let real_width = self.width + self.radius * 2.0;
-                    let real_height = self.height + self.radius * 2.0;
-                    b.add_rounded_rectangle(
-                        &Rect::new(
-                            Point::new(-real_width / 2.0, -real_height / 2.0),
-                            Size::new(real_width, real_height),
-                        ),
-                        &BorderRadii {
-                            top_left: self.radius,
-                            top_right: self.radius,
-                            bottom_left: self.radius,
-                            bottom_right: self.radius,
-                        },
-                        lyon_path::Winding::Positive,
-                    );
-                }
+            None if !overlay.is_empty => {
+                *path = ShapePath::build_as(&Segments(Vec::new()));
+                overlay.is_empty = true;
             }
+            None => {}
+        }
+    }
 
-            builder = builder.add(&RoundedRectangle {
-                width: 2.0 * half_extends.x,
-                height: 2.0 * half_extends.y,
-                radius: border_radius.unwrap_or(0.0),
+    for (color, segments) in by_color {
+        // Spawned after the transform propagation, the global transform must be set explicitly
+        let transform = Transform::from_translation(Vec3::Z * OVERLAY_Z);
+        commands
+            .spawn_bundle(GeometryBuilder::build_as(
+                &segments,
+                DrawMode::Fill(FillMode {
+                    color,
+                    options: FillOptions::default(),
+                }),
+                transform,
+            ))
+            .insert(GlobalTransform::from(transform))
+            .insert(IsOverlay {
+                color,
+                is_empty: false,
             });
+    }
+}
+
+/// Segments of [`LINE_WIDTH`] (used by the overlays)
+struct Segments(Vec<(Vec2, Vec2)>);
+
+impl Geometry for Segments {
+    fn add_geometry(&self, builder: &mut Builder) {
+        for (a, b) in &self.0 {
+            thick_segment(*a, *b).add_geometry(builder);
         }
-        CollisionShape::ConvexHull { .. } => {
-            if let Some(polygon) = shape.as_convex_polygon() {
-                builder = builder.add(&shapes::Polygon {
-                    points: polygon.points().into_bevy(),
-                    closed: true,
-                });
-
-            // TODO: Implement better rounded convex hull renderer. Currently our strategy is to
-            // render a circle at each point on the hull to give an impression of what the
-            // border radius adds to the hull, but we don't currently fill in the empty space
-            // around the edges of the polygon that are also taken up by the border radius.
-            } else if let Some(polygon) = shape.as_round_convex_polygon() {
-                for point in polygon.base_shape.points() {
-                    builder = builder.add(&shapes::Circle {
-                        radius: polygon.border_radius,
-                        center: point.into_bevy(),
-                    });
+    }
+}
+
+struct ShapeGeometry<'a> {
+    body: &'a CollisionShape,
+    shape: &'a dyn Shape,
+}
+
+impl Geometry for ShapeGeometry<'_> {
+    #[allow(clippy::too_many_lines)]
+    fn add_geometry(&self, builder: &mut Builder) {
+        match self.body {
+            CollisionShape::Sphere { radius } => {
+                shapes::Circle {
+                    radius: *radius,
+                    center: Vec2::ZERO,
+                }
+                .add_geometry(builder);
+            }
+            CollisionShape::Capsule {
+                half_segment,
+                radius,
+            } => {
+                Capsule {
+                    half_segment: *half_segment,
+                    radius: *radius,
+                }
+                .add_geometry(builder);
+            }
+            CollisionShape::Cuboid {
+                half_extends,
+                border_radius,
+            } => {
+                struct RoundedRectangle {
+                    width: f32,
+                    height: f32,
+                    radius: f32,
+                }
+                impl Geometry for RoundedRectangle {
+                    fn add_geometry(&self, b: &mut Builder) {
+                        let real_width = self.width + self.radius * 2.0;
+                        let real_height = self.height + self.radius * 2.0;
+                        b.add_rounded_rectangle(
+                            &Rect::new(
+                                Point::new(-real_width / 2.0, -real_height / 2.0),
+                                Size::new(real_width, real_height),
+                            ),
+                            &BorderRadii {
+                                top_left: self.radius,
+                                top_right: self.radius,
+                                bottom_left: self.radius,
+                                bottom_right: self.radius,
+                            },
+                            lyon_path::Winding::Positive,
+                        );
+                    }
                 }
 
-                builder = builder.add(&shapes::Polygon {
-                    points: polygon.base_shape.points().into_bevy(),
-                    closed: true,
-                });
+                RoundedRectangle {
+                    width: 2.0 * half_extends.x,
+                    height: 2.0 * half_extends.y,
+                    radius: border_radius.unwrap_or(0.0),
+                }
+                .add_geometry(builder);
             }
-        }
-        CollisionShape::HeightField { size, heights } => {
-            if let Some(heights) = heights.get(0) {
-                let mut points: Vec<Vec2> = Vec::with_capacity(heights.len() + 2);
-                let mut min_y = f32::MAX;
-                let half_size = size.x * 0.5;
-                let len = (heights.len() - 1) as f32;
-
-                heights
-                    .iter()
-                    .enumerate()
-                    .map(|(i, p)| Vec2::new((i as f32) * size.x / len - half_size, *p))
-                    .for_each(|p| {
-                        if p.y < min_y {
-                            min_y = p.y;
+            CollisionShape::ConvexHull { .. } => {
+                if let Some(polygon) = self.shape.as_convex_polygon() {
+                    shapes::Polygon {
+                        points: polygon.points().into_bevy(),
+                        closed: true,
+                    }
+                    .add_geometry(builder);
+
+                // TODO: Implement better rounded convex hull renderer. Currently our strategy is to
+                // render a circle at each point on the hull to give an impression of what the
+                // border radius adds to the hull, but we don't currently fill in the empty space
+                // around the edges of the polygon that are also taken up by the border radius.
+                } else if let Some(polygon) = self.shape.as_round_convex_polygon() {
+                    for point in polygon.base_shape.points() {
+                        shapes::Circle {
+                            radius: polygon.border_radius,
+                            center: point.into_bevy(),
                         }
-                        points.push(p);
-                    });
+                        .add_geometry(builder);
+                    }
 
-                points.push(Vec2::new(half_size, min_y));
-                points.push(Vec2::new(-half_size, min_y));
+                    shapes::Polygon {
+                        points: polygon.base_shape.points().into_bevy(),
+                        closed: true,
+                    }
+                    .add_geometry(builder);
+                }
+            }
+            CollisionShape::HeightField { size, heights } => {
+                if let Some(heights) = heights.get(0) {
+                    let mut points: Vec<Vec2> = Vec::with_capacity(heights.len() + 2);
+                    let mut min_y = f32::MAX;
+                    let half_size = size.x * 0.5;
+                    let len = (heights.len() - 1) as f32;
+
+                    heights
+                        .iter()
+                        .enumerate()
+                        .map(|(i, p)| Vec2::new((i as f32) * size.x / len - half_size, *p))
+                        .for_each(|p| {
+                            if p.y < min_y {
+                                min_y = p.y;
+                            }
+                            points.push(p);
+                        });
 
-                builder = builder.add(&shapes::Polygon {
-                    points,
+                    points.push(Vec2::new(half_size, min_y));
+                    points.push(Vec2::new(-half_size, min_y));
+
+                    shapes::Polygon {
+                        points,
+                        closed: true,
+                    }
+                    .add_geometry(builder);
+                }
+            }
+            CollisionShape::Segment { a, b } => {
+                thick_segment(a.truncate(), b.truncate()).add_geometry(builder);
+            }
+            CollisionShape::Triangle { a, b, c } => {
+                shapes::Polygon {
+                    points: vec![a.truncate(), b.truncate(), c.truncate()],
                     closed: true,
-                });
+                }
+                .add_geometry(builder);
+            }
+            CollisionShape::Polyline { vertices, indices } => match indices {
+                Some(indices) => {
+                    for [i1, i2] in indices {
+                        if let (Some(a), Some(b)) =
+                            (vertices.get(*i1 as usize), vertices.get(*i2 as usize))
+                        {
+                            thick_segment(a.truncate(), b.truncate()).add_geometry(builder);
+                        }
+                    }
+                }
+                None => {
+                    for pair in vertices.windows(2) {
+                        thick_segment(pair[0].truncate(), pair[1].truncate()).add_geometry(builder);
+                    }
+                }
+            },
+            CollisionShape::TriMesh { vertices, indices } => {
+                for triangle in indices {
+                    // The triangles referring to a vertex out of bounds are ignored
+                    if let Some(points) = triangle
+                        .iter()
+                        .map(|i| vertices.get(*i as usize).map(|v| v.truncate()))
+                        .collect()
+                    {
+                        shapes::Polygon {
+                            points,
+                            closed: true,
+                        }
+                        .add_geometry(builder);
+                    }
+                }
+            }
+            CollisionShape::Compound { shapes } => {
+                if let Some(compound) = self.shape.as_compound() {
+                    let children = shapes.iter().filter(|child| !child.shape.is_composite());
+                    for (child, (position, child_shape)) in children.zip(compound.shapes()) {
+                        Offset {
+                            geometry: ShapeGeometry {
+                                body: &child.shape,
+                                shape: &**child_shape,
+                            },
+                            transform: Transform2D::rotation(Angle::radians(
+                                position.rotation.angle(),
+                            ))
+                            .then_translate(Vector::new(
+                                position.translation.x,
+                                position.translation.y,
+                            )),
+                        }
+                        .add_geometry(builder);
+                    }
+                }
+            }
+            any_other => {
+                warn!(
+                    "Debug render for this shape {:?} is unimplemented",
+                    any_other
+                );
             }
         }
-        any_other => {
-            warn!(
-                "Debug render for this shape {:?} is unimplemented",
-                any_other
-            );
-        }
-    };
+    }
+}
 
-    builder
+/// Rectangle of [`LINE_WIDTH`] around the segment `a`-`b`
+fn thick_segment(a: Vec2, b: Vec2) -> shapes::Polygon {
+    let normal = (b - a).perp().normalize_or_zero() * (LINE_WIDTH / 2.0);
+    shapes::Polygon {
+        points: vec![a + normal, b + normal, b - normal, a - normal],
+        closed: true,
+    }
+}
+
+/// Geometry drawn with an offset relative to the entity (used by the compound shapes)
+struct Offset<G> {
+    geometry: G,
+    transform: Transform2D,
+}
+
+impl<G: Geometry> Geometry for Offset<G> {
+    fn add_geometry(&self, b: &mut Builder) {
+        let mut path = lyon_path::Path::builder();
+        self.geometry.add_geometry(&mut path);
+        for event in path.build().iter() {
+            match event {
+                PathEvent::Begin { at } => {
+                    b.begin(self.transform.transform_point(at));
+                }
+                PathEvent::Line { to, .. } => {
+                    b.line_to(self.transform.transform_point(to));
+                }
+                PathEvent::Quadratic { ctrl, to, .. } => {
+                    b.quadratic_bezier_to(
+                        self.transform.transform_point(ctrl),
+                        self.transform.transform_point(to),
+                    );
+                }
+                PathEvent::Cubic {
+                    ctrl1, ctrl2, to, ..
+                } => {
+                    b.cubic_bezier_to(
+                        self.transform.transform_point(ctrl1),
+                        self.transform.transform_point(ctrl2),
+                        self.transform.transform_point(to),
+                    );
+                }
+                PathEvent::End { close, .. } => {
+                    b.end(close);
+                }
+            }
+        }
+    }
 }
 
 struct Capsule {
@@ -273,3 +492,62 @@ impl Geometry for Capsule {
         path.end(true);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::core::CorePlugin;
+    use bevy::reflect::TypeRegistryArc;
+
+    use heron_core::{PhysicsSteps, PhysicsWorldId};
+    use heron_rapier::{PhysicsWorlds, RapierPlugin};
+
+    use super::*;
+
+    #[test]
+    fn shapes_of_additional_worlds_are_not_drawn() {
+        let mut app = App::new();
+        app.init_resource::<TypeRegistryArc>()
+            .insert_resource(PhysicsSteps::every_frame(Duration::from_secs_f32(
+                1.0 / 60.0,
+            )))
+            .add_plugin(CorePlugin)
+            .add_plugin(RapierPlugin)
+            .init_resource::<DebugColor>()
+            .init_resource::<DebugEntityMap>()
+            .add_system_set_to_stage(CoreStage::PostUpdate, systems());
+        app.world
+            .get_resource_mut::<PhysicsWorlds>()
+            .unwrap()
+            .add(PhysicsWorldId(1));
+
+        let spawn = |app: &mut App, shape: CollisionShape| {
+            app.world
+                .spawn()
+                .insert_bundle((
+                    Transform::default(),
+                    GlobalTransform::default(),
+                    RigidBody::Static,
+                    shape,
+                ))
+                .id()
+        };
+        let default_world = spawn(&mut app, CollisionShape::Sphere { radius: 1.0 });
+        let other_world = spawn(
+            &mut app,
+            CollisionShape::ConvexHull {
+                points: vec![Vec3::ZERO, Vec3::X, Vec3::Y],
+                border_radius: None,
+            },
+        );
+        app.world.entity_mut(other_world).insert(PhysicsWorldId(1));
+
+        for _ in 0..3 {
+            app.update();
+        }
+
+        assert!(app.world.get::<HasDebug>(default_world).is_some());
+        assert!(app.world.get::<HasDebug>(other_world).is_none());
+    }
+}
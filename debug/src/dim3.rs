@@ -1,85 +1,278 @@
 use bevy::prelude::*;
 use bevy_prototype_debug_lines::DebugLines;
+use fnv::FnvHashSet;
 
 use heron_core::{CollisionShape, RigidBody, SensorShape};
+use heron_rapier::convert::IntoBevy;
+use heron_rapier::rapier3d::geometry::{ColliderSet, Shape, ShapeType};
+use heron_rapier::ColliderHandleMap;
 
+use crate::overlays::OverlaySegments;
 use crate::shape3d_wireframe::{
     add_capsule, add_cone, add_convex_hull, add_cuboid, add_cylinder, add_height_field,
-    add_rounded_cuboid, add_sphere,
+    add_polyline, add_rounded_convex_hull, add_rounded_cuboid, add_sphere, add_triangle,
+    add_trimesh,
 };
 
 use super::DebugColor;
 
+/// Only the shapes of the default physics world are drawn, as the colliders of the additional
+/// worlds are not in the `ColliderSet` resource
 fn add_shape_outlines(
     shapes: Query<
         '_,
         '_,
         (
+            Entity,
             &CollisionShape,
             &GlobalTransform,
             Option<&RigidBody>,
             Option<&SensorShape>,
         ),
     >,
+    colliders: Res<'_, ColliderSet>,
+    handles: Res<'_, ColliderHandleMap>,
     color: Res<'_, DebugColor>,
     mut lines: ResMut<'_, DebugLines>,
+    mut unsupported: Local<'_, FnvHashSet<Entity>>,
 ) {
-    for (shape, trans, rigid_body_option, sensor_option) in shapes.iter() {
-        let origin = trans.translation;
-        let orient = trans.rotation;
+    for (entity, shape, trans, rigid_body_option, sensor_option) in shapes.iter() {
+        let collider = match handles
+            .get(&entity)
+            .and_then(|handle| colliders.get(*handle))
+        {
+            Some(collider) => collider,
+            None => continue,
+        };
         let color = color.for_collider_type(rigid_body_option, sensor_option.is_some());
-        match shape {
-            CollisionShape::Cuboid {
-                half_extends,
-                border_radius,
-            } => match border_radius {
-                Some(bevel) => {
-                    add_rounded_cuboid(origin, orient, *half_extends, *bevel, color, &mut lines);
-                }
-                None => {
-                    add_cuboid(origin, orient, *half_extends, color, &mut lines);
+        if let CollisionShape::Custom { .. } = shape {
+            // The custom shapes are only known by rapier once built
+            let result = add_rapier_shape(
+                collider.shape(),
+                trans.translation,
+                trans.rotation,
+                color,
+                &mut lines,
+            );
+
+            // Warn only once per entity, as the shapes are drawn again every frame
+            if let Err(shape_type) = result {
+                if unsupported.insert(entity) {
+                    warn!(
+                        "Debug render for this custom shape {:?} is unimplemented",
+                        shape_type
+                    );
                 }
-            },
-            CollisionShape::Sphere { radius } => {
-                add_sphere(origin, orient, *radius, color, &mut lines);
             }
-            CollisionShape::Capsule {
-                half_segment,
-                radius,
-            } => add_capsule(origin, orient, *half_segment, *radius, color, &mut lines),
-            CollisionShape::ConvexHull {
-                points,
-                border_radius: _,
-            } => {
-                // NOTE: won't work with ConvexHull with border_radius set,
-                // absolutely no idea how to handle it here
-                add_convex_hull(origin, orient, points, color, &mut lines);
+        } else {
+            add_shape(
+                &shape.scaled(trans.scale),
+                trans.translation,
+                trans.rotation,
+                color,
+                &mut lines,
+            );
+        }
+    }
+}
+
+fn add_shape(
+    shape: &CollisionShape,
+    origin: Vec3,
+    orient: Quat,
+    color: Color,
+    lines: &mut DebugLines,
+) {
+    match shape {
+        CollisionShape::Cuboid {
+            half_extends,
+            border_radius,
+        } => match border_radius {
+            Some(bevel) => {
+                add_rounded_cuboid(origin, orient, *half_extends, *bevel, color, lines);
             }
-            CollisionShape::HeightField { size, heights } => {
-                add_height_field(origin, orient, *size, heights, color, &mut lines);
+            None => {
+                add_cuboid(origin, orient, *half_extends, color, lines);
             }
-            CollisionShape::Cone {
-                half_height,
-                radius,
-            } => {
-                add_cone(origin, orient, *half_height, *radius, color, &mut lines);
+        },
+        CollisionShape::Sphere { radius } => {
+            add_sphere(origin, orient, *radius, color, lines);
+        }
+        CollisionShape::Capsule {
+            half_segment,
+            radius,
+        } => add_capsule(origin, orient, *half_segment, *radius, color, lines),
+        CollisionShape::ConvexHull {
+            points,
+            border_radius,
+        } => match border_radius {
+            Some(radius) => {
+                add_rounded_convex_hull(origin, orient, points, *radius, color, lines);
             }
-            CollisionShape::Cylinder {
-                half_height,
-                radius,
-            } => {
-                add_cylinder(origin, orient, *half_height, *radius, color, &mut lines);
+            None => {
+                add_convex_hull(origin, orient, points, color, lines);
             }
-            any_other => {
-                warn!(
-                    "Debug render for this shape {:?} is unimplemented",
-                    any_other
+        },
+        CollisionShape::HeightField { size, heights } => {
+            add_height_field(origin, orient, *size, heights, color, lines);
+        }
+        CollisionShape::Cone {
+            half_height,
+            radius,
+        } => {
+            add_cone(origin, orient, *half_height, *radius, color, lines);
+        }
+        CollisionShape::Cylinder {
+            half_height,
+            radius,
+        } => {
+            add_cylinder(origin, orient, *half_height, *radius, color, lines);
+        }
+        CollisionShape::Segment { a, b } => {
+            let p0 = origin + orient.mul_vec3(*a);
+            let p1 = origin + orient.mul_vec3(*b);
+            lines.line_colored(p0, p1, 0.0, color);
+        }
+        CollisionShape::Triangle { a, b, c } => {
+            add_triangle(origin, orient, [*a, *b, *c], color, lines);
+        }
+        CollisionShape::Polyline { vertices, indices } => {
+            add_polyline(origin, orient, vertices, indices.as_deref(), color, lines);
+        }
+        CollisionShape::TriMesh { vertices, indices } => {
+            add_trimesh(origin, orient, vertices, indices, color, lines);
+        }
+        CollisionShape::Compound { shapes } => {
+            for child in shapes.iter().filter(|child| !child.shape.is_composite()) {
+                add_shape(
+                    &child.shape,
+                    origin + orient.mul_vec3(child.translation),
+                    orient * child.rotation,
+                    color,
+                    lines,
                 );
             }
         }
+        any_other => {
+            warn!(
+                "Debug render for this shape {:?} is unimplemented",
+                any_other
+            );
+        }
     }
 }
 
+/// Draws a shape built by rapier (used for the custom shapes)
+///
+/// Returns the type of the first (sub)shape that cannot be drawn, if any.
+fn add_rapier_shape(
+    shape: &dyn Shape,
+    origin: Vec3,
+    orient: Quat,
+    color: Color,
+    lines: &mut DebugLines,
+) -> Result<(), ShapeType> {
+    let point = |point: &heron_rapier::rapier3d::math::Point<f32>| (*point).into_bevy();
+    if let Some(ball) = shape.as_ball() {
+        add_sphere(origin, orient, ball.radius, color, lines);
+    } else if let Some(cuboid) = shape.as_cuboid() {
+        add_cuboid(
+            origin,
+            orient,
+            cuboid.half_extents.into_bevy(),
+            color,
+            lines,
+        );
+    } else if let Some(cuboid) = shape.as_round_cuboid() {
+        add_rounded_cuboid(
+            origin,
+            orient,
+            cuboid.base_shape.half_extents.into_bevy(),
+            cuboid.border_radius,
+            color,
+            lines,
+        );
+    } else if let Some(capsule) = shape.as_capsule() {
+        let (a, b) = (point(&capsule.segment.a), point(&capsule.segment.b));
+        let axis = Quat::from_rotation_arc(Vec3::Y, (b - a).normalize_or_zero());
+        add_capsule(
+            origin + orient.mul_vec3((a + b) / 2.0),
+            orient * axis,
+            a.distance(b) / 2.0,
+            capsule.radius,
+            color,
+            lines,
+        );
+    } else if let Some(cone) = shape.as_cone() {
+        add_cone(origin, orient, cone.half_height, cone.radius, color, lines);
+    } else if let Some(cylinder) = shape.as_cylinder() {
+        add_cylinder(
+            origin,
+            orient,
+            cylinder.half_height,
+            cylinder.radius,
+            color,
+            lines,
+        );
+    } else if let Some(segment) = shape.as_segment() {
+        let p0 = origin + orient.mul_vec3(point(&segment.a));
+        let p1 = origin + orient.mul_vec3(point(&segment.b));
+        lines.line_colored(p0, p1, 0.0, color);
+    } else if let Some(triangle) = shape.as_triangle() {
+        let vertices = [point(&triangle.a), point(&triangle.b), point(&triangle.c)];
+        add_triangle(origin, orient, vertices, color, lines);
+    } else if let Some(polyhedron) = shape.as_convex_polyhedron() {
+        let points: Vec<Vec3> = polyhedron.points().iter().map(point).collect();
+        add_convex_hull(origin, orient, &points, color, lines);
+    } else if let Some(polyhedron) = shape.as_round_convex_polyhedron() {
+        let points: Vec<Vec3> = polyhedron.base_shape.points().iter().map(point).collect();
+        add_rounded_convex_hull(
+            origin,
+            orient,
+            &points,
+            polyhedron.border_radius,
+            color,
+            lines,
+        );
+    } else if let Some(polyline) = shape.as_polyline() {
+        let vertices: Vec<Vec3> = polyline.vertices().iter().map(point).collect();
+        add_polyline(
+            origin,
+            orient,
+            &vertices,
+            Some(polyline.indices()),
+            color,
+            lines,
+        );
+    } else if let Some(trimesh) = shape.as_trimesh() {
+        let vertices: Vec<Vec3> = trimesh.vertices().iter().map(point).collect();
+        add_trimesh(origin, orient, &vertices, trimesh.indices(), color, lines);
+    } else if let Some(compound) = shape.as_compound() {
+        let mut result = Ok(());
+        for (position, child) in compound.shapes() {
+            let (translation, rotation) = (*position).into_bevy();
+            let child_result = add_rapier_shape(
+                &**child,
+                origin + orient.mul_vec3(translation),
+                orient * rotation,
+                color,
+                lines,
+            );
+            result = result.and(child_result);
+        }
+        return result;
+    } else {
+        return Err(shape.shape_type());
+    }
+    Ok(())
+}
+
 pub(crate) fn systems() -> SystemSet {
     SystemSet::new().with_system(add_shape_outlines.system())
 }
+
+pub(crate) fn draw_overlays(segments: Res<'_, OverlaySegments>, mut lines: ResMut<'_, DebugLines>) {
+    for (start, end, color) in &segments.0 {
+        lines.line_colored(*start, *end, 0.0, *color);
+    }
+}
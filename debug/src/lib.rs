@@ -21,12 +21,73 @@ mod dim2;
 #[cfg(feature = "3d")]
 mod dim3;
 
+#[cfg(any(feature = "2d", feature = "3d"))]
+mod overlays;
+
 #[cfg(feature = "3d")]
 mod shape3d_wireframe;
 
 /// Plugin that enables rendering of collision shapes
+///
+/// Only the collision shapes of the default physics world are rendered. The ones belonging to one
+/// of the additional [`PhysicsWorlds`](heron_rapier::PhysicsWorlds) are ignored.
+///
+/// Additional overlays can be enabled with [`DebugPlugin::with_overlays`].
 #[derive(Debug, Copy, Clone, Default)]
-pub struct DebugPlugin(DebugColor);
+pub struct DebugPlugin(DebugColor, DebugOverlays);
+
+/// Resource defining which overlays are drawn on top of the collision shapes
+///
+/// None of the overlays is drawn by default. They can be enabled when installing the plugin (see
+/// [`DebugPlugin::with_overlays`]), or toggled at runtime by mutating this resource.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_debug::DebugOverlays;
+/// fn toggle_contacts(keys: Res<Input<KeyCode>>, mut overlays: ResMut<DebugOverlays>) {
+///     if keys.just_pressed(KeyCode::C) {
+///         overlays.contacts = !overlays.contacts;
+///     }
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct DebugOverlays {
+    /// Contact points and normals of the shapes in contact
+    pub contacts: bool,
+
+    /// Axis-aligned bounding boxes of the collision shapes, as used by the broad phase
+    pub aabbs: bool,
+
+    /// Linear and angular velocities of the entities having a [`Velocity`](heron_core::Velocity)
+    pub velocities: bool,
+
+    /// Centers of mass of the dynamic rigid bodies
+    pub centers_of_mass: bool,
+}
+
+impl DebugOverlays {
+    /// Enable all overlays
+    #[must_use]
+    pub fn all() -> Self {
+        Self {
+            contacts: true,
+            aabbs: true,
+            velocities: true,
+            centers_of_mass: true,
+        }
+    }
+}
+
+impl DebugPlugin {
+    /// Draw the given overlays on top of the collision shapes
+    #[must_use]
+    pub fn with_overlays(mut self, overlays: DebugOverlays) -> Self {
+        self.1 = overlays;
+        self
+    }
+}
 
 #[allow(dead_code)]
 #[derive(Debug, Copy, Clone)]
@@ -35,6 +96,17 @@ struct DebugColor {
     static_body: Color,
     dynamic_body: Color,
     kinematic_body: Color,
+    contact: Color,
+    aabb: Color,
+    linear_velocity: Color,
+    angular_velocity: Color,
+    center_of_mass: Color,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, SystemLabel)]
+enum DebugSystem {
+    CollectOverlays,
 }
 
 type DebugEntityMap = FnvHashMap<Entity, Entity>;
@@ -54,14 +126,36 @@ struct Indexed;
 impl Plugin for DebugPlugin {
     fn build(&self, app: &mut App) {
         #[cfg(feature = "3d")]
-        app.add_plugin(bevy_prototype_debug_lines::DebugLinesPlugin)
-            .add_system_set_to_stage(CoreStage::PostUpdate, dim3::systems());
+        app.add_plugin(bevy_prototype_debug_lines::DebugLinesPlugin::default())
+            .add_system_set_to_stage(CoreStage::PostUpdate, dim3::systems())
+            .add_system_to_stage(
+                CoreStage::Last,
+                dim3::draw_overlays
+                    .system()
+                    .after(DebugSystem::CollectOverlays),
+            );
 
         #[cfg(all(feature = "2d", not(feature = "3d")))]
         app.add_plugin(bevy_prototype_lyon::plugin::ShapePlugin)
-            .add_system_set_to_stage(CoreStage::PostUpdate, dim2::systems());
+            .add_system_set_to_stage(CoreStage::PostUpdate, dim2::systems())
+            .add_system_to_stage(
+                CoreStage::Last,
+                dim2::draw_overlays
+                    .system()
+                    .after(DebugSystem::CollectOverlays),
+            );
+
+        #[cfg(any(feature = "2d", feature = "3d"))]
+        app.init_resource::<overlays::OverlaySegments>()
+            .add_system_to_stage(
+                CoreStage::Last,
+                overlays::collect_segments
+                    .system()
+                    .label(DebugSystem::CollectOverlays),
+            );
 
         app.insert_resource(self.0)
+            .insert_resource(self.1)
             .init_resource::<DebugEntityMap>()
             .add_system_to_stage(CoreStage::Last, track_debug_entities.system())
             .add_system_to_stage(CoreStage::Last, scale_debug_entities.system());
@@ -105,6 +199,11 @@ impl Default for DebugColor {
             static_body: Color::rgba(0.64, 0.0, 0.16, DEFAULT_DEBUG_ALPHA),
             dynamic_body: Color::rgba(0.0, 0.18, 0.54, DEFAULT_DEBUG_ALPHA),
             kinematic_body: Color::rgba(0.21, 0.07, 0.7, DEFAULT_DEBUG_ALPHA),
+            contact: Color::rgb(1.0, 0.84, 0.0),
+            aabb: Color::rgb(0.5, 0.5, 0.5),
+            linear_velocity: Color::rgb(0.0, 0.8, 0.8),
+            angular_velocity: Color::rgb(0.8, 0.0, 0.8),
+            center_of_mass: Color::rgb(1.0, 0.5, 0.0),
         }
     }
 }
@@ -0,0 +1,150 @@
+use bevy::prelude::*;
+
+use heron_core::Velocity;
+use heron_rapier::convert::IntoBevy;
+
+#[cfg(all(feature = "2d", not(feature = "3d")))]
+use heron_rapier::rapier2d as rapier;
+#[cfg(feature = "3d")]
+use heron_rapier::rapier3d as rapier;
+
+use rapier::dynamics::RigidBodySet;
+use rapier::geometry::{ColliderSet, NarrowPhase};
+use rapier::math::{Isometry, Point};
+
+use super::{DebugColor, DebugOverlays};
+
+/// Number of axes drawn for the point markers and the AABBs
+const DIMENSIONS: usize = if cfg!(feature = "3d") { 3 } else { 2 };
+
+// 2d games usually use pixels as units, where 3d games use meters
+const MARKER_SIZE: f32 = if cfg!(feature = "3d") { 0.2 } else { 10.0 };
+const NORMAL_LENGTH: f32 = if cfg!(feature = "3d") { 0.5 } else { 20.0 };
+#[cfg(all(feature = "2d", not(feature = "3d")))]
+const ANGULAR_VELOCITY_RADIUS: f32 = 20.0;
+
+/// Line segments of the overlays, collected each frame to be drawn by the 2d or 3d renderer
+#[derive(Default)]
+pub(crate) struct OverlaySegments(pub(crate) Vec<(Vec3, Vec3, Color)>);
+
+impl OverlaySegments {
+    fn line(&mut self, start: Vec3, end: Vec3, color: Color) {
+        self.0.push((start, end, color));
+    }
+
+    fn cross(&mut self, center: Vec3, color: Color) {
+        for axis in [Vec3::X, Vec3::Y, Vec3::Z].iter().take(DIMENSIONS) {
+            let half = *axis * (MARKER_SIZE / 2.0);
+            self.line(center - half, center + half, color);
+        }
+    }
+
+    /// Edges of the box going from `mins` to `maxs`
+    fn aabb(&mut self, mins: Vec3, maxs: Vec3, color: Color) {
+        let corner = |index: usize| {
+            Vec3::new(
+                if index & 1 == 0 { mins.x } else { maxs.x },
+                if index & 2 == 0 { mins.y } else { maxs.y },
+                if index & 4 == 0 { mins.z } else { maxs.z },
+            )
+        };
+        for index in 0..(1 << DIMENSIONS) {
+            for axis in 0..DIMENSIONS {
+                if index & (1 << axis) == 0 {
+                    self.line(corner(index), corner(index | (1 << axis)), color);
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "3d")]
+    fn angular_velocity(&mut self, origin: Vec3, velocity: Vec3, color: Color) {
+        self.line(origin, origin + velocity, color);
+    }
+
+    /// Arc around the origin, as long as the angle travelled in one second
+    #[cfg(all(feature = "2d", not(feature = "3d")))]
+    fn angular_velocity(&mut self, origin: Vec3, velocity: Vec3, color: Color) {
+        const SEGMENTS: usize = 16;
+        let sweep = velocity
+            .z
+            .clamp(-std::f32::consts::TAU, std::f32::consts::TAU);
+        let arc_point = |index: usize| {
+            let angle = sweep * index as f32 / SEGMENTS as f32;
+            origin + Vec3::new(angle.cos(), angle.sin(), 0.0) * ANGULAR_VELOCITY_RADIUS
+        };
+        for index in 0..SEGMENTS {
+            self.line(arc_point(index), arc_point(index + 1), color);
+        }
+    }
+}
+
+pub(crate) fn collect_segments(
+    overlays: Res<'_, DebugOverlays>,
+    colors: Res<'_, DebugColor>,
+    bodies: Res<'_, RigidBodySet>,
+    colliders: Res<'_, ColliderSet>,
+    narrow_phase: Res<'_, NarrowPhase>,
+    velocities: Query<'_, '_, (&GlobalTransform, &Velocity)>,
+    mut segments: ResMut<'_, OverlaySegments>,
+) {
+    segments.0.clear();
+
+    if overlays.contacts {
+        for pair in narrow_phase
+            .contact_pairs()
+            .filter(|pair| pair.has_any_active_contact)
+        {
+            if let Some(collider) = colliders.get(pair.collider1) {
+                for manifold in &pair.manifolds {
+                    // The points and normal are relative to the subshape in contact, which is
+                    // offset from the collider in composite shapes
+                    let subshape = collider.position()
+                        * manifold.subshape_pos1.unwrap_or_else(Isometry::identity);
+                    let normal: Vec3 = (subshape * manifold.local_n1).into_bevy();
+                    for contact in &manifold.points {
+                        let point = to_bevy_point(subshape * contact.local_p1);
+                        segments.cross(point, colors.contact);
+                        segments.line(point, point + normal * NORMAL_LENGTH, colors.contact);
+                    }
+                }
+            }
+        }
+    }
+
+    if overlays.aabbs {
+        for (_, collider) in colliders.iter() {
+            let aabb = collider.compute_aabb();
+            segments.aabb(
+                to_bevy_point(aabb.mins),
+                to_bevy_point(aabb.maxs),
+                colors.aabb,
+            );
+        }
+    }
+
+    if overlays.velocities {
+        for (transform, velocity) in velocities.iter() {
+            let origin = transform.translation;
+            segments.line(origin, origin + velocity.linear, colors.linear_velocity);
+            segments.angular_velocity(origin, velocity.angular.axis(), colors.angular_velocity);
+        }
+    }
+
+    if overlays.centers_of_mass {
+        for (_, body) in bodies.iter().filter(|(_, body)| body.is_dynamic()) {
+            let center = body.position() * body.mass_properties().local_com;
+            segments.cross(to_bevy_point(center), colors.center_of_mass);
+        }
+    }
+}
+
+#[cfg(all(feature = "2d", not(feature = "3d")))]
+fn to_bevy_point(point: Point<f32>) -> Vec3 {
+    point.into_bevy().extend(0.0)
+}
+
+#[cfg(feature = "3d")]
+fn to_bevy_point(point: Point<f32>) -> Vec3 {
+    point.into_bevy()
+}
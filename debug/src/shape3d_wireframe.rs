@@ -319,3 +319,106 @@ pub(crate) fn add_convex_hull(
         lines.line_colored(p1, p2, 0.0, color);
     }
 }
+pub(crate) fn add_polyline(
+    origin: Vec3,
+    orient: Quat,
+    vertices: &[Vec3],
+    indices: Option<&[[u32; 2]]>,
+    color: Color,
+    lines: &mut DebugLines,
+) {
+    let point = |i: u32| {
+        vertices
+            .get(i as usize)
+            .map(|vertex| origin + orient.mul_vec3(*vertex))
+    };
+    match indices {
+        Some(indices) => {
+            for &[i1, i2] in indices {
+                if let (Some(p1), Some(p2)) = (point(i1), point(i2)) {
+                    lines.line_colored(p1, p2, 0.0, color);
+                }
+            }
+        }
+        None => {
+            for pair in vertices.windows(2) {
+                let p0 = origin + orient.mul_vec3(pair[0]);
+                let p1 = origin + orient.mul_vec3(pair[1]);
+                lines.line_colored(p0, p1, 0.0, color);
+            }
+        }
+    }
+}
+pub(crate) fn add_trimesh(
+    origin: Vec3,
+    orient: Quat,
+    vertices: &[Vec3],
+    indices: &[[u32; 3]],
+    color: Color,
+    lines: &mut DebugLines,
+) {
+    let vertex = |i: u32| vertices.get(i as usize).copied();
+    for &[i0, i1, i2] in indices {
+        // The triangles referring to a vertex out of bounds are ignored
+        if let (Some(v0), Some(v1), Some(v2)) = (vertex(i0), vertex(i1), vertex(i2)) {
+            add_triangle(origin, orient, [v0, v1, v2], color, lines);
+        }
+    }
+}
+pub(crate) fn add_triangle(
+    origin: Vec3,
+    orient: Quat,
+    vertices: [Vec3; 3],
+    color: Color,
+    lines: &mut DebugLines,
+) {
+    let [p0, p1, p2] = vertices.map(|vertex| origin + orient.mul_vec3(vertex));
+    // NOTE: Triangles shared by a mesh create duplicate lines
+    lines.line_colored(p0, p1, 0.0, color);
+    lines.line_colored(p1, p2, 0.0, color);
+    lines.line_colored(p2, p0, 0.0, color);
+}
+pub(crate) fn add_rounded_convex_hull(
+    origin: Vec3,
+    orient: Quat,
+    points: &[Vec3],
+    radius: f32,
+    color: Color,
+    lines: &mut DebugLines,
+) {
+    let points3d: Vec<_> = points.into_rapier();
+    let (vertex, faces) = convex_hull(&points3d);
+    let vertex: Vec<Vec3> = vertex.into_iter().map(IntoBevy::into_bevy).collect();
+    if vertex.is_empty() {
+        return;
+    }
+    let center = vertex.iter().fold(Vec3::ZERO, |sum, point| sum + *point) / vertex.len() as f32;
+
+    // The faces of the hull, pushed outward by the border radius
+    for face in &faces {
+        let [p0, p1, p2] = face.map(|i| vertex[i as usize]);
+        let mut normal = (p1 - p0).cross(p2 - p0).normalize_or_zero();
+        if normal.dot(p0 - center) < 0.0 {
+            normal = -normal;
+        }
+        let offset = normal * radius;
+        add_triangle(
+            origin,
+            orient,
+            [p0 + offset, p1 + offset, p2 + offset],
+            color,
+            lines,
+        );
+    }
+
+    // The rounded corners
+    for point in &vertex {
+        add_sphere(
+            origin + orient.mul_vec3(*point),
+            orient,
+            radius,
+            color,
+            lines,
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+
+use heron::*;
+
+fn main() {
+    App::new()
+        .insert_resource(Gravity::from(Vec3::new(0., -9.81, 0.)))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(PhysicsPlugin::default()) // Add the plugin
+        .add_startup_system(spawn.system())
+        .run();
+}
+
+fn spawn(mut commands: Commands) {
+    commands.spawn_bundle(PerspectiveCameraBundle {
+        transform: Transform::from_xyz(0.0, 6.0, 18.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ..Default::default()
+    });
+
+    // Sphere
+    commands
+        .spawn_bundle((Transform::default(), GlobalTransform::default()))
+        .insert(CollisionShape::Sphere { radius: 1.0 })
+        .insert(RigidBody::Dynamic);
+
+    // Rounded cuboid
+    commands
+        .spawn_bundle((
+            Transform::from_translation(Vec3::X * 4.0),
+            GlobalTransform::default(),
+        ))
+        .insert(CollisionShape::Cuboid {
+            half_extends: Vec3::ONE,
+            border_radius: Some(0.2),
+        })
+        .insert(RigidBody::Dynamic);
+
+    // Capsule
+    commands
+        .spawn_bundle((
+            Transform::from_translation(Vec3::X * -4.0),
+            GlobalTransform::default(),
+        ))
+        .insert(CollisionShape::Capsule {
+            radius: 0.5,
+            half_segment: 1.0,
+        })
+        .insert(RigidBody::Dynamic);
+
+    // Rounded convex hull, a random tetrahedron
+    commands
+        .spawn_bundle((
+            Transform::from_translation(Vec3::Y * 4.0),
+            GlobalTransform::default(),
+        ))
+        .insert(CollisionShape::ConvexHull {
+            points: vec![
+                Vec3::new(0.0, -1.0, 0.0),
+                Vec3::new(1.0, 0.0, 0.0),
+                Vec3::new(-1.0, 0.0, 0.5),
+                Vec3::new(0.0, 0.5, -1.0),
+            ],
+            border_radius: Some(0.1),
+        })
+        .insert(RigidBody::Dynamic);
+
+    // Ground
+    commands
+        .spawn_bundle((
+            Transform::from_translation(Vec3::Y * -4.0),
+            GlobalTransform::default(),
+        ))
+        .insert(CollisionShape::Cuboid {
+            half_extends: Vec3::new(10.0, 0.5, 10.0),
+            border_radius: None,
+        })
+        .insert(RigidBody::Static);
+}
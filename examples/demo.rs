@@ -69,7 +69,9 @@ fn spawn(mut commands: Commands) {
         .insert(PhysicMaterial {
             restitution: 0.7,
             ..Default::default()
-        });
+        })
+        // Fire the collision events of this body (logged by the system below)
+        .insert(ActiveCollisionEvents::default());
 }
 
 fn log_collisions(mut events: EventReader<CollisionEvent>) {
@@ -92,7 +92,9 @@ fn spawn_player(mut commands: Commands) {
             border_radius: None,
         })
         .insert(Velocity::default())
-        .insert(CollisionLayers::new(Layer::Player, Layer::Enemy));
+        .insert(CollisionLayers::new(Layer::Player, Layer::Enemy))
+        // Collision events are only fired for the shapes that activate them
+        .insert(ActiveCollisionEvents::default());
 }
 
 fn spawn_enemy(mut commands: Commands) {
@@ -2,6 +2,7 @@ use bevy::prelude::*;
 
 use heron_core::{utils::NearZero, Acceleration};
 
+use crate::body::HandleMap;
 use crate::convert::IntoRapier;
 use crate::rapier::dynamics::RigidBodySet;
 use crate::rapier::{
@@ -11,10 +12,14 @@ use crate::rapier::{
 
 pub(crate) fn update_rapier_force_and_torque(
     mut bodies: ResMut<'_, RigidBodySet>,
-    accelerations: Query<'_, '_, (&super::RigidBodyHandle, &Acceleration)>,
+    handles: Res<'_, HandleMap>,
+    accelerations: Query<'_, '_, (Entity, &Acceleration), With<super::RigidBodyHandle>>,
 ) {
-    for (handle, acceleration) in accelerations.iter() {
-        if let Some(body) = bodies.get_mut(handle.0) {
+    for (entity, acceleration) in accelerations.iter() {
+        if let Some(body) = handles
+            .get(&entity)
+            .and_then(|handle| bodies.get_mut(*handle))
+        {
             update_acceleration(body, acceleration);
         }
     }
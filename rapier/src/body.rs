@@ -1,14 +1,20 @@
 use bevy::ecs::prelude::*;
+use bevy::log::prelude::*;
+use bevy::math::prelude::*;
 use bevy::transform::prelude::*;
 use fnv::FnvHashMap;
 
-use heron_core::{Damping, PhysicMaterial, RigidBody, RotationConstraints, Velocity};
+use heron_core::{
+    ContinuousCollisionDetection, Damping, GravityScale, MassProperties, PhysicsWorldId, RigidBody,
+    RotationConstraints, Sleeping, TranslationConstraints, Velocity,
+};
 
 use crate::convert::{IntoBevy, IntoRapier};
 use crate::rapier::dynamics::{
     IslandManager, JointSet, RigidBodyBuilder, RigidBodyHandle, RigidBodySet, RigidBodyType,
 };
 use crate::rapier::geometry::ColliderSet;
+use crate::world::ActiveWorld;
 
 pub(crate) type HandleMap = FnvHashMap<Entity, RigidBodyHandle>;
 
@@ -17,24 +23,50 @@ pub(crate) fn create(
     mut commands: Commands<'_, '_>,
     mut bodies: ResMut<'_, RigidBodySet>,
     mut handles: ResMut<'_, HandleMap>,
+    world: Res<'_, ActiveWorld>,
     query: Query<
         '_,
         '_,
         (
             Entity,
+            Option<&PhysicsWorldId>,
             &GlobalTransform,
             &RigidBody,
             Option<&Velocity>,
             Option<&Damping>,
             Option<&RotationConstraints>,
+            Option<&TranslationConstraints>,
+            Option<&MassProperties>,
+            Option<&Sleeping>,
+            Option<&ContinuousCollisionDetection>,
+            Option<&GravityScale>,
         ),
         Without<super::RigidBodyHandle>,
     >,
 ) {
-    for (entity, transform, body, velocity, damping, rotation_constraints) in query.iter() {
+    for (
+        entity,
+        world_id,
+        transform,
+        body,
+        velocity,
+        damping,
+        rotation_constraints,
+        translation_constraints,
+        mass_properties,
+        sleeping,
+        ccd,
+        gravity_scale,
+    ) in query.iter()
+    {
+        if !world.contains(world_id) {
+            continue;
+        }
+
         let mut builder = RigidBodyBuilder::new(body_status(*body))
             .user_data(entity.to_bits().into())
-            .position((transform.translation, transform.rotation).into_rapier());
+            .position((transform.translation, transform.rotation).into_rapier())
+            .ccd_enabled(ccd.is_some());
 
         #[allow(unused_variables)]
         if let Some(RotationConstraints {
@@ -53,6 +85,13 @@ pub(crate) fn create(
             }
         }
 
+        if translation_constraints
+            .copied()
+            .map_or(false, crate::constraints::is_locked)
+        {
+            builder = builder.lock_translations();
+        }
+
         if let Some(v) = velocity {
             builder = builder
                 .linvel(v.linear.into_rapier())
@@ -63,7 +102,25 @@ pub(crate) fn create(
             builder = builder.linear_damping(d.linear).angular_damping(d.angular);
         }
 
-        let rigid_body_handle = bodies.insert(builder.build());
+        if let Some(scale) = gravity_scale {
+            builder = builder.gravity_scale(scale.0);
+        }
+
+        if let Some(mass_properties) = mass_properties {
+            builder = builder.additional_mass_properties(crate::mass::to_rapier(mass_properties));
+        }
+
+        if let Some(sleeping) = sleeping {
+            builder = builder.sleeping(sleeping.sleeping);
+        }
+
+        let mut rigid_body = builder.build();
+
+        if let Some(sleeping) = sleeping {
+            crate::sleep::set_threshold(&mut rigid_body, *sleeping);
+        }
+
+        let rigid_body_handle = bodies.insert(rigid_body);
 
         handles.insert(entity, rigid_body_handle);
         commands
@@ -72,6 +129,30 @@ pub(crate) fn create(
     }
 }
 
+/// The world of a body cannot be changed once it is created, the body would stay in its former
+/// world
+pub(crate) fn warn_world_changes(
+    query: Query<
+        '_,
+        '_,
+        (
+            Entity,
+            &PhysicsWorldId,
+            ChangeTrackers<super::RigidBodyHandle>,
+        ),
+        Changed<PhysicsWorldId>,
+    >,
+) {
+    for (entity, world_id, handle_tracker) in query.iter() {
+        if !handle_tracker.is_added() {
+            warn!(
+                "The physics world of {:?} was changed to {:?} after the creation of its rigid body. It stays in its former world.",
+                entity, world_id
+            );
+        }
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn remove_invalids_after_components_removed(
     mut commands: Commands<'_, '_>,
@@ -82,14 +163,16 @@ pub(crate) fn remove_invalids_after_components_removed(
     mut joints: ResMut<'_, JointSet>,
     bodies_removed: RemovedComponents<'_, RigidBody>,
     constraints_removed: RemovedComponents<'_, RotationConstraints>,
-    materials_removed: RemovedComponents<'_, PhysicMaterial>,
+    translation_constraints_removed: RemovedComponents<'_, TranslationConstraints>,
+    mass_properties_removed: RemovedComponents<'_, MassProperties>,
     rb_entities: Query<'_, '_, Entity, With<super::RigidBodyHandle>>,
     collider_entities: Query<'_, '_, Entity, With<super::ColliderHandle>>,
 ) {
     bodies_removed
         .iter()
         .chain(constraints_removed.iter())
-        .chain(materials_removed.iter())
+        .chain(translation_constraints_removed.iter())
+        .chain(mass_properties_removed.iter())
         .for_each(|entity| {
             if let Some(handle) = handles.remove(&entity) {
                 remove_collider_handles(
@@ -120,27 +203,29 @@ pub(crate) fn remove_invalids_after_component_changed(
     changed: Query<
         '_,
         '_,
-        (Entity, &super::RigidBodyHandle),
+        Entity,
         Or<(
             Changed<RigidBody>,
             Changed<RotationConstraints>,
-            Changed<PhysicMaterial>,
+            Changed<TranslationConstraints>,
+            Added<MassProperties>,
         )>,
     >,
 ) {
-    for (entity, handle) in changed.iter() {
-        remove_collider_handles(
-            &mut commands,
-            &collider_entities,
-            &bodies,
-            &colliders,
-            handle.0,
-        );
-        bodies.remove(handle.0, &mut islands, &mut colliders, &mut joints);
-        if rigidbody_entities.get(entity).is_ok() {
-            commands.entity(entity).remove::<super::RigidBodyHandle>();
-        }
-        handles.remove(&entity);
+    for entity in changed.iter() {
+        if let Some(handle) = handles.remove(&entity) {
+            remove_collider_handles(
+                &mut commands,
+                &collider_entities,
+                &bodies,
+                &colliders,
+                handle,
+            );
+            bodies.remove(handle, &mut islands, &mut colliders, &mut joints);
+            if rigidbody_entities.get(entity).is_ok() {
+                commands.entity(entity).remove::<super::RigidBodyHandle>();
+            }
+        }
     }
 }
 
@@ -171,10 +256,27 @@ fn remove_collider_handles(
 
 pub(crate) fn update_rapier_position(
     mut bodies: ResMut<'_, RigidBodySet>,
-    query: Query<'_, '_, (&GlobalTransform, &super::RigidBodyHandle), Changed<GlobalTransform>>,
+    handles: Res<'_, HandleMap>,
+    query: Query<
+        '_,
+        '_,
+        (
+            Entity,
+            &GlobalTransform,
+            Option<&crate::interpolation::Poses>,
+        ),
+        (Changed<GlobalTransform>, With<super::RigidBodyHandle>),
+    >,
 ) {
-    for (transform, handle) in query.iter() {
-        if let Some(body) = bodies.get_mut(handle.0) {
+    for (entity, transform, poses) in query.iter() {
+        if poses.map_or(false, |poses| poses.is_rendered(transform)) {
+            continue;
+        }
+
+        if let Some(body) = handles
+            .get(&entity)
+            .and_then(|handle| bodies.get_mut(*handle))
+        {
             let isometry = (transform.translation, transform.rotation).into_rapier();
             if body.is_kinematic() {
                 body.set_next_kinematic_position(isometry);
@@ -187,23 +289,25 @@ pub(crate) fn update_rapier_position(
 
 pub(crate) fn update_bevy_transform(
     bodies: Res<'_, RigidBodySet>,
+    handles: Res<'_, HandleMap>,
     mut query: Query<
         '_,
         '_,
         (
+            Entity,
             Option<&mut Transform>,
             &mut GlobalTransform,
-            &super::RigidBodyHandle,
             Option<&RigidBody>,
         ),
+        With<super::RigidBodyHandle>,
     >,
 ) {
-    for (mut local, mut global, handle, body_type) in query.iter_mut() {
+    for (entity, local, mut global, body_type) in query.iter_mut() {
         if !body_type.copied().unwrap_or_default().can_have_velocity() {
             continue;
         }
 
-        let body = match bodies.get(handle.0) {
+        let body = match handles.get(&entity).and_then(|handle| bodies.get(*handle)) {
             None => continue,
             Some(body) => body,
         };
@@ -219,28 +323,37 @@ pub(crate) fn update_bevy_transform(
             translation.z = global.translation.z;
         }
 
-        if translation == global.translation && rotation == global.rotation {
-            continue;
-        }
+        set_bevy_transform(local, &mut global, translation, rotation);
+    }
+}
 
-        if let Some(local) = &mut local {
-            if local.translation == global.translation {
-                local.translation = translation;
-            } else {
-                local.translation = translation - (global.translation - local.translation);
-            }
+/// Set the global translation and rotation, updating the local transform accordingly
+pub(crate) fn set_bevy_transform(
+    local: Option<Mut<'_, Transform>>,
+    global: &mut Mut<'_, GlobalTransform>,
+    translation: Vec3,
+    rotation: Quat,
+) {
+    if translation == global.translation && rotation == global.rotation {
+        return;
+    }
 
-            if local.rotation == global.rotation {
-                local.rotation = rotation;
-            } else {
-                local.rotation =
-                    rotation * (global.rotation * local.rotation.conjugate()).conjugate();
-            }
+    if let Some(mut local) = local {
+        if local.translation == global.translation {
+            local.translation = translation;
+        } else {
+            local.translation = translation - (global.translation - local.translation);
         }
 
-        global.translation = translation;
-        global.rotation = rotation;
+        if local.rotation == global.rotation {
+            local.rotation = rotation;
+        } else {
+            local.rotation = rotation * (global.rotation * local.rotation.conjugate()).conjugate();
+        }
     }
+
+    global.translation = translation;
+    global.rotation = rotation;
 }
 
 fn body_status(body_type: RigidBody) -> RigidBodyType {
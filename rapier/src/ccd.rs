@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+use heron_core::ContinuousCollisionDetection;
+
+use crate::body::HandleMap;
+use crate::rapier::dynamics::RigidBodySet;
+use crate::RigidBodyHandle;
+
+pub(crate) fn enable_ccd(
+    mut bodies: ResMut<'_, RigidBodySet>,
+    handles: Res<'_, HandleMap>,
+    query: Query<'_, '_, Entity, (Added<ContinuousCollisionDetection>, With<RigidBodyHandle>)>,
+) {
+    for entity in query.iter() {
+        if let Some(body) = handles
+            .get(&entity)
+            .and_then(|handle| bodies.get_mut(*handle))
+        {
+            body.enable_ccd(true);
+        }
+    }
+}
+
+pub(crate) fn disable_ccd(
+    mut bodies: ResMut<'_, RigidBodySet>,
+    handles: Res<'_, HandleMap>,
+    removed: RemovedComponents<'_, ContinuousCollisionDetection>,
+) {
+    removed
+        .iter()
+        .filter_map(|entity| handles.get(&entity))
+        .for_each(|handle| {
+            if let Some(body) = bodies.get_mut(*handle) {
+                body.enable_ccd(false);
+            }
+        });
+}
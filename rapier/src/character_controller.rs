@@ -0,0 +1,191 @@
+use bevy::prelude::*;
+
+use heron_core::utils::NearZero;
+use heron_core::{CharacterController, CollisionLayers, CollisionShape};
+
+use crate::body::HandleMap;
+use crate::pipeline::{PhysicsWorld, ShapeCastCollisionType};
+
+pub(crate) fn update_character_controllers(
+    physics_world: PhysicsWorld<'_, '_>,
+    handles: Res<'_, HandleMap>,
+    mut controllers: Query<
+        '_,
+        '_,
+        (
+            Entity,
+            &CollisionShape,
+            &GlobalTransform,
+            &mut Transform,
+            &mut CharacterController,
+            Option<&CollisionLayers>,
+        ),
+    >,
+) {
+    for (entity, shape, global, mut transform, mut controller, layers) in controllers.iter_mut() {
+        // Only the controllers of the world being updated can be resolved against it
+        if !handles.contains_key(&entity) {
+            continue;
+        }
+
+        let mover = Mover {
+            world: &physics_world,
+            entity,
+            shape,
+            rotation: global.rotation,
+            layers: layers.copied().unwrap_or_default(),
+            controller: &controller,
+        };
+
+        let (position, grounded, touching) =
+            mover.move_and_slide(global.translation, controller.translation);
+
+        let delta = position - global.translation;
+        if !delta.is_near_zero() {
+            transform.translation += delta;
+        }
+
+        controller.translation = Vec3::ZERO;
+        controller.set_outcome(grounded, touching);
+    }
+}
+
+struct Mover<'a, 'w, 's> {
+    world: &'a PhysicsWorld<'w, 's>,
+    entity: Entity,
+    shape: &'a CollisionShape,
+    rotation: Quat,
+    layers: CollisionLayers,
+    controller: &'a CharacterController,
+}
+
+struct Hit {
+    entity: Entity,
+    /// Distance traveled before the hit
+    distance: f32,
+    /// Surface normal of the obstacle, in world space
+    ///
+    /// `None` if the shapes were already penetrating each other
+    normal: Option<Vec3>,
+}
+
+impl<'a, 'w, 's> Mover<'a, 'w, 's> {
+    fn move_and_slide(&self, start: Vec3, translation: Vec3) -> (Vec3, bool, Vec<Entity>) {
+        let up = self.controller.up.normalize_or_zero();
+        let mut position = start;
+        let mut remaining = translation;
+        let mut grounded = false;
+        let mut touching = Vec::new();
+
+        for _ in 0..self.controller.max_iterations {
+            if remaining.is_near_zero() {
+                break;
+            }
+
+            let hit = if let Some(hit) = self.cast(position, remaining) {
+                hit
+            } else {
+                position += remaining;
+                break;
+            };
+
+            if !touching.contains(&hit.entity) {
+                touching.push(hit.entity);
+            }
+
+            let length = remaining.length();
+            let direction = remaining / length;
+            let travel = (hit.distance - self.controller.offset).clamp(0.0, length);
+            position += direction * travel;
+            remaining -= direction * travel;
+
+            let normal = if let Some(normal) = hit.normal {
+                normal
+            } else {
+                // Let the character escape from an obstacle it is already penetrating
+                position += remaining;
+                break;
+            };
+
+            if self.controller.is_walkable(normal) {
+                grounded = true;
+            } else if let Some(stepped) = self.climb_step(position, remaining, up) {
+                position = stepped;
+                grounded = true;
+                break;
+            }
+
+            // Slide along the obstacle
+            remaining -= normal * remaining.dot(normal);
+        }
+
+        if !grounded {
+            grounded = self.is_on_ground(position, up);
+        }
+
+        (position, grounded, touching)
+    }
+
+    /// Returns the position after climbing a step, if there is a step that can be climbed
+    fn climb_step(&self, position: Vec3, remaining: Vec3, up: Vec3) -> Option<Vec3> {
+        if self.controller.step_height <= 0.0 {
+            return None;
+        }
+
+        let horizontal = remaining - up * remaining.dot(up);
+        if horizontal.is_near_zero() {
+            return None;
+        }
+
+        let step_up = up * self.controller.step_height;
+        if self.cast(position, step_up).is_some() {
+            return None;
+        }
+
+        let raised = position + step_up;
+        if self.cast(raised, horizontal).is_some() {
+            return None;
+        }
+
+        let advanced = raised + horizontal;
+        let hit = self.cast(advanced, -step_up)?;
+        match hit.normal {
+            Some(normal) if self.controller.is_walkable(normal) => {
+                Some(advanced - up * (hit.distance - self.controller.offset).max(0.0))
+            }
+            _ => None,
+        }
+    }
+
+    fn is_on_ground(&self, position: Vec3, up: Vec3) -> bool {
+        self.cast(position, -up * self.controller.offset * 2.0)
+            .and_then(|hit| hit.normal)
+            .map_or(false, |normal| self.controller.is_walkable(normal))
+    }
+
+    fn cast(&self, from: Vec3, movement: Vec3) -> Option<Hit> {
+        let info = self.world.shape_cast_with_filter(
+            self.shape,
+            from,
+            self.rotation,
+            movement,
+            self.layers,
+            |entity| entity != self.entity,
+        )?;
+
+        Some(match info.collision_type {
+            ShapeCastCollisionType::Collided(collision) => Hit {
+                entity: info.entity,
+                distance: (collision.self_end_position - from).length(),
+                // Rapier reports the obstacle as the first shape of the time of impact, so the
+                // `self_normal` is actually the world-space normal of the obstacle surface
+                normal: Some(collision.self_normal.normalize_or_zero()),
+            },
+            ShapeCastCollisionType::AlreadyPenetrating => Hit {
+                entity: info.entity,
+                distance: 0.0,
+                normal: None,
+            },
+        })
+    }
+}
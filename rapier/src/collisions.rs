@@ -0,0 +1,98 @@
+use bevy::ecs::prelude::*;
+
+use heron_core::{CollisionData, CollisionEvent, CollisionEventQueue, Collisions};
+
+use crate::pipeline::collision_data;
+use crate::rapier::dynamics::RigidBodySet;
+use crate::rapier::geometry::{ColliderHandle, ColliderSet, NarrowPhase};
+
+pub(crate) fn update_collisions(
+    bodies: Res<'_, RigidBodySet>,
+    colliders: Res<'_, ColliderSet>,
+    narrow_phase: Res<'_, NarrowPhase>,
+    body_handles: Res<'_, super::body::HandleMap>,
+    collider_handles: Res<'_, super::shape::HandleMap>,
+    mut query: Query<'_, '_, (Entity, &mut Collisions)>,
+) {
+    for (entity, mut collisions) in query.iter_mut() {
+        let own: Vec<ColliderHandle> = if let Some(body) = body_handles
+            .get(&entity)
+            .and_then(|handle| bodies.get(*handle))
+        {
+            body.colliders().to_vec()
+        } else if let Some(handle) = collider_handles.get(&entity) {
+            vec![*handle]
+        } else {
+            // The entity doesn't belong to the physics world being updated
+            continue;
+        };
+
+        let mut others: Vec<CollisionData> = Vec::new();
+        for handle in own.iter().copied() {
+            let touching = narrow_phase
+                .contacts_with(handle)
+                .filter(|pair| pair.has_any_active_contact)
+                .map(|pair| other_collider(handle, pair.collider1, pair.collider2));
+
+            let intersecting = narrow_phase
+                .intersections_with(handle)
+                .filter(|(_, _, intersecting)| *intersecting)
+                .map(|(h1, h2, _)| other_collider(handle, h1, h2));
+
+            for other in touching.chain(intersecting) {
+                if own.contains(&other) {
+                    continue;
+                }
+                if let Some(data) = collision_data(&bodies, &colliders, other) {
+                    if !others.contains(&data) {
+                        others.push(data);
+                    }
+                }
+            }
+        }
+
+        // Keep a stable order, so that the component is only marked as changed when the contacts do
+        others.sort_by_key(CollisionData::collision_shape_entity);
+        let updated = Collisions::new(others);
+        if *collisions != updated {
+            *collisions = updated;
+        }
+    }
+}
+
+fn other_collider(own: ColliderHandle, h1: ColliderHandle, h2: ColliderHandle) -> ColliderHandle {
+    if h1 == own {
+        h2
+    } else {
+        h1
+    }
+}
+
+/// Pushes the collision events in the queues of the entities involved
+///
+/// It runs once per frame, after all physics worlds are stepped, so that each event is only read
+/// once.
+pub(crate) fn fill_event_queues(
+    mut events: EventReader<'_, '_, CollisionEvent>,
+    mut queues: Query<'_, '_, &mut CollisionEventQueue>,
+) {
+    for event in events.iter() {
+        let (d1, d2) = event.data();
+        let entities = [
+            d1.rigid_body_entity(),
+            d1.collision_shape_entity(),
+            d2.rigid_body_entity(),
+            d2.collision_shape_entity(),
+        ];
+
+        for (index, entity) in entities.iter().copied().enumerate() {
+            if entities[..index].contains(&entity) {
+                continue;
+            }
+            if let (Ok(mut queue), Some(event)) = (queues.get_mut(entity), event.for_entity(entity))
+            {
+                queue.push(event);
+            }
+        }
+    }
+}
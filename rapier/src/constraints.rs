@@ -0,0 +1,67 @@
+use bevy::ecs::prelude::*;
+use bevy::math::Vec3;
+use bevy::transform::prelude::*;
+
+use heron_core::TranslationConstraints;
+
+use crate::convert::{IntoBevy, IntoRapier};
+use crate::rapier::dynamics::RigidBodySet;
+
+/// Rapier can only lock the translations along all the axes at once (which is done when creating
+/// the body). When only some axes are locked, the movement along them is cancelled after each step.
+pub(crate) fn apply_translation_constraints(
+    mut bodies: ResMut<'_, RigidBodySet>,
+    handles: Res<'_, super::body::HandleMap>,
+    query: Query<
+        '_,
+        '_,
+        (Entity, &TranslationConstraints, &GlobalTransform),
+        With<super::RigidBodyHandle>,
+    >,
+) {
+    for (entity, constraints, transform) in query.iter() {
+        if is_locked(*constraints) {
+            continue;
+        }
+
+        let allowed = Vec3::new(
+            axis_factor(constraints.allow_x),
+            axis_factor(constraints.allow_y),
+            axis_factor(constraints.allow_z),
+        );
+
+        if allowed == Vec3::ONE {
+            continue;
+        }
+
+        if let Some(body) = handles
+            .get(&entity)
+            .and_then(|handle| bodies.get_mut(*handle))
+        {
+            let velocity: Vec3 = (*body.linvel()).into_bevy();
+            body.set_linvel((velocity * allowed).into_rapier(), false);
+
+            // The global transform still contains the position from before the step
+            let translation: Vec3 = (*body.translation()).into_bevy();
+            let constrained = translation * allowed + transform.translation * (Vec3::ONE - allowed);
+            body.set_translation(constrained.into_rapier(), false);
+        }
+    }
+}
+
+/// Returns true if the translations are locked along all the axes of the simulation
+pub(crate) fn is_locked(constraints: TranslationConstraints) -> bool {
+    #[cfg(dim2)]
+    let locked = !constraints.allow_x && !constraints.allow_y;
+    #[cfg(dim3)]
+    let locked = !constraints.allow_x && !constraints.allow_y && !constraints.allow_z;
+    locked
+}
+
+fn axis_factor(allowed: bool) -> f32 {
+    if allowed {
+        1.0
+    } else {
+        0.0
+    }
+}
@@ -7,13 +7,17 @@
 
 use bevy::math::prelude::*;
 
-use heron_core::{AxisAngle, CollisionLayers};
+use heron_core::{
+    ActiveCollisionEvents, ActiveCollisionHooks, AxisAngle, CollisionLayers, CombineRule,
+};
 
 use crate::nalgebra::{
     self, Point2, Point3, Quaternion, UnitComplex, UnitQuaternion, Vector2, Vector3,
 };
+use crate::rapier::dynamics::CoefficientCombineRule;
 use crate::rapier::geometry::InteractionGroups;
 use crate::rapier::math::{Isometry, Translation, Vector};
+use crate::rapier::pipeline::{ActiveEvents, ActiveHooks};
 
 pub trait IntoBevy<T> {
     #[must_use]
@@ -187,6 +191,42 @@ impl IntoBevy<CollisionLayers> for InteractionGroups {
     }
 }
 
+impl IntoRapier<ActiveHooks> for ActiveCollisionHooks {
+    fn into_rapier(self) -> ActiveHooks {
+        let mut hooks = ActiveHooks::empty();
+        hooks.set(ActiveHooks::FILTER_CONTACT_PAIRS, self.filter_contact_pairs);
+        hooks.set(
+            ActiveHooks::FILTER_INTERSECTION_PAIR,
+            self.filter_intersection_pairs,
+        );
+        hooks.set(
+            ActiveHooks::MODIFY_SOLVER_CONTACTS,
+            self.modify_solver_contacts,
+        );
+        hooks
+    }
+}
+
+impl IntoRapier<ActiveEvents> for ActiveCollisionEvents {
+    fn into_rapier(self) -> ActiveEvents {
+        let mut events = ActiveEvents::empty();
+        events.set(ActiveEvents::CONTACT_EVENTS, self.contacts);
+        events.set(ActiveEvents::INTERSECTION_EVENTS, self.intersections);
+        events
+    }
+}
+
+impl IntoRapier<CoefficientCombineRule> for CombineRule {
+    fn into_rapier(self) -> CoefficientCombineRule {
+        match self {
+            CombineRule::Average => CoefficientCombineRule::Average,
+            CombineRule::Min => CoefficientCombineRule::Min,
+            CombineRule::Multiply => CoefficientCombineRule::Multiply,
+            CombineRule::Max => CoefficientCombineRule::Max,
+        }
+    }
+}
+
 #[cfg(feature = "2d")]
 impl IntoRapier<rapier2d::dynamics::RigidBodyHandle> for crate::RigidBodyHandle {
     #[cfg(not(feature = "3d"))]
@@ -225,6 +265,25 @@ impl IntoRapier<rapier3d::geometry::ColliderHandle> for crate::ColliderHandle {
     }
 }
 
+#[cfg(feature = "2d")]
+impl IntoRapier<rapier2d::dynamics::JointHandle> for crate::JointHandle {
+    #[cfg(not(feature = "3d"))]
+    fn into_rapier(self) -> rapier2d::dynamics::JointHandle {
+        self.0
+    }
+    #[cfg(feature = "3d")]
+    fn into_rapier(self) -> rapier2d::dynamics::JointHandle {
+        rapier2d::dynamics::JointHandle::invalid()
+    }
+}
+
+#[cfg(feature = "3d")]
+impl IntoRapier<rapier3d::dynamics::JointHandle> for crate::JointHandle {
+    fn into_rapier(self) -> rapier3d::dynamics::JointHandle {
+        self.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(dim3)]
@@ -2,15 +2,20 @@ use bevy::prelude::*;
 
 use heron_core::Damping;
 
+use crate::body::HandleMap;
 use crate::rapier::dynamics::{RigidBodyDamping, RigidBodySet};
 use crate::RigidBodyHandle;
 
 pub(crate) fn update_rapier_damping(
     mut bodies: ResMut<'_, RigidBodySet>,
-    dampings: Query<'_, '_, (&RigidBodyHandle, &Damping), Changed<Damping>>,
+    handles: Res<'_, HandleMap>,
+    dampings: Query<'_, '_, (Entity, &Damping), (Changed<Damping>, With<RigidBodyHandle>)>,
 ) {
-    for (handle, damping) in dampings.iter() {
-        if let Some(body) = bodies.get_mut(handle.0) {
+    for (entity, damping) in dampings.iter() {
+        if let Some(body) = handles
+            .get(&entity)
+            .and_then(|handle| bodies.get_mut(*handle))
+        {
             body.set_linear_damping(damping.linear);
             body.set_angular_damping(damping.angular);
         }
@@ -19,14 +24,14 @@ pub(crate) fn update_rapier_damping(
 
 pub(crate) fn reset_rapier_damping(
     mut bodies: ResMut<'_, RigidBodySet>,
-    handles: Query<'_, '_, &RigidBodyHandle>,
+    handles: Res<'_, HandleMap>,
     removed: RemovedComponents<'_, Damping>,
 ) {
     removed
         .iter()
-        .filter_map(|entity| handles.get(entity).ok())
+        .filter_map(|entity| handles.get(&entity))
         .for_each(|handle| {
-            if let Some(body) = bodies.get_mut(handle.0) {
+            if let Some(body) = bodies.get_mut(*handle) {
                 body.set_linear_damping(RigidBodyDamping::default().linear_damping);
                 body.set_angular_damping(RigidBodyDamping::default().angular_damping);
             }
@@ -0,0 +1,96 @@
+use bevy::prelude::*;
+use fnv::{FnvHashMap, FnvHashSet};
+
+use heron_core::{combine_gravity, Gravity, GravityScale, GravityZone};
+
+use crate::convert::{IntoBevy, IntoRapier};
+use crate::rapier::dynamics::{RigidBody, RigidBodyHandle, RigidBodySet};
+use crate::rapier::geometry::{Collider, ColliderSet, NarrowPhase};
+use crate::rapier::math::Vector;
+use crate::{body, shape};
+
+pub(crate) fn update_gravity_scale(
+    mut bodies: ResMut<'_, RigidBodySet>,
+    handles: Res<'_, body::HandleMap>,
+    query: Query<
+        '_,
+        '_,
+        (Entity, &GravityScale),
+        (Changed<GravityScale>, With<super::RigidBodyHandle>),
+    >,
+) {
+    for (entity, scale) in query.iter() {
+        if let Some(body) = handles
+            .get(&entity)
+            .and_then(|handle| bodies.get_mut(*handle))
+        {
+            body.set_gravity_scale(scale.0, true);
+        }
+    }
+}
+
+pub(crate) fn reset_gravity_scale(
+    mut bodies: ResMut<'_, RigidBodySet>,
+    handles: Res<'_, body::HandleMap>,
+    removed: RemovedComponents<'_, GravityScale>,
+) {
+    removed
+        .iter()
+        .filter_map(|entity| handles.get(&entity))
+        .for_each(|handle| {
+            if let Some(body) = bodies.get_mut(*handle) {
+                body.set_gravity_scale(GravityScale::default().0, true);
+            }
+        });
+}
+
+pub(crate) fn apply_gravity_zones(
+    gravity: Res<'_, Gravity>,
+    mut bodies: ResMut<'_, RigidBodySet>,
+    colliders: Res<'_, ColliderSet>,
+    narrow_phase: Res<'_, NarrowPhase>,
+    handles: Res<'_, shape::HandleMap>,
+    zones: Query<'_, '_, (Entity, &GravityZone, &GlobalTransform), With<super::ColliderHandle>>,
+) {
+    let mut affected: FnvHashMap<RigidBodyHandle, Vec<(bool, Vec3)>> = FnvHashMap::default();
+
+    for (entity, zone, transform) in zones.iter() {
+        let zone_handle = match handles.get(&entity) {
+            Some(handle) => *handle,
+            None => continue,
+        };
+
+        let bodies_in_zone: FnvHashSet<RigidBodyHandle> = narrow_phase
+            .intersections_with(zone_handle)
+            .filter(|(_, _, intersecting)| *intersecting)
+            .map(|(c1, c2, _)| if c1 == zone_handle { c2 } else { c1 })
+            .filter_map(|collider| colliders.get(collider).and_then(Collider::parent))
+            .filter(|body| bodies.get(*body).map_or(false, RigidBody::is_dynamic))
+            .collect();
+
+        for body_handle in bodies_in_zone {
+            let body = &bodies[body_handle];
+            let center_of_mass = body.position() * body.mass_properties().local_com;
+
+            #[cfg(dim2)]
+            let position = center_of_mass.into_bevy().extend(0.0);
+            #[cfg(dim3)]
+            let position = center_of_mass.into_bevy();
+
+            affected.entry(body_handle).or_default().push((
+                zone.replace,
+                zone.gravity_at(transform.translation, position),
+            ));
+        }
+    }
+
+    let world_gravity = gravity.vector();
+    for (body_handle, zones) in affected {
+        if let Some(body) = bodies.get_mut(body_handle) {
+            let extra_gravity = combine_gravity(world_gravity, zones) - world_gravity;
+            let force: Vector<f32> =
+                (extra_gravity * body.gravity_scale() * body.mass()).into_rapier();
+            body.apply_force(force, true);
+        }
+    }
+}
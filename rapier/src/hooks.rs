@@ -0,0 +1,235 @@
+use bevy::ecs::entity::Entity;
+use bevy::math::Vec3;
+
+use crate::convert::{IntoBevy, IntoRapier};
+use crate::rapier::dynamics::{RigidBodyHandle, RigidBodySet};
+use crate::rapier::geometry::{ColliderHandle, ColliderSet, SolverContact, SolverFlags};
+use crate::rapier::pipeline;
+
+/// User-defined hooks, called by the physics engine during the physics step
+///
+/// The hooks are only called for the pairs of collision shapes that activate them with the
+/// [`ActiveCollisionHooks`](heron_core::ActiveCollisionHooks) component.
+///
+/// Install them by inserting the [`PhysicsHooksResource`] resource.
+///
+/// The hooks are called in the middle of the physics step, and don't have access to the ECS world.
+/// If they need game data (e.g. the owner of a projectile), it can be stored in the hooks
+/// themselves, behind a lock updated by regular systems.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_rapier::{ContactModificationContext, PhysicsHooks, PhysicsHooksResource};
+/// struct OneWayPlatforms;
+///
+/// impl PhysicsHooks for OneWayPlatforms {
+///     fn modify_solver_contacts(&self, context: &mut ContactModificationContext<'_, '_>) {
+///         // Let the bodies go through the platform from below
+///         if context.normal().y < 0.0 {
+///             context.clear();
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///   App::new()
+///     .insert_resource(PhysicsHooksResource::new(OneWayPlatforms))
+///     // ...
+///     .run();
+/// }
+/// ```
+pub trait PhysicsHooks: Send + Sync + 'static {
+    /// Returns false if the contacts between the two collision shapes should not be computed
+    fn filter_contact_pair(&self, _context: &PairFilterContext) -> bool {
+        true
+    }
+
+    /// Returns false if the intersection between the two collision shapes (at least one of them
+    /// being a sensor) should not be reported
+    fn filter_intersection_pair(&self, _context: &PairFilterContext) -> bool {
+        true
+    }
+
+    /// Modify the contacts between two collision shapes before they are solved
+    fn modify_solver_contacts(&self, _context: &mut ContactModificationContext<'_, '_>) {}
+}
+
+/// Resource that holds the [`PhysicsHooks`] used by the physics step
+pub struct PhysicsHooksResource(Box<dyn PhysicsHooks>);
+
+impl PhysicsHooksResource {
+    /// Create the resource from the given hooks
+    #[must_use]
+    pub fn new(hooks: impl PhysicsHooks) -> Self {
+        Self(Box::new(hooks))
+    }
+}
+
+/// Pair of collision shapes that may be in contact
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PairFilterContext {
+    /// Entity of the first collision shape
+    pub collision_shape1: Entity,
+
+    /// Entity of the second collision shape
+    pub collision_shape2: Entity,
+
+    /// Entity of the rigid body of the first collision shape
+    pub rigid_body1: Option<Entity>,
+
+    /// Entity of the rigid body of the second collision shape
+    pub rigid_body2: Option<Entity>,
+}
+
+/// Contacts between two collision shapes, that can be modified before being solved
+pub struct ContactModificationContext<'a, 'b> {
+    pair: PairFilterContext,
+    normal: Vec3,
+    solver_contacts: &'a mut Vec<SolverContact>,
+    user_data: &'b mut u32,
+}
+
+impl<'a, 'b> ContactModificationContext<'a, 'b> {
+    /// Entities of the collision shapes and rigid bodies in contact
+    #[must_use]
+    pub fn pair(&self) -> PairFilterContext {
+        self.pair
+    }
+
+    /// Contact normal, in world space, pointing from the first collision shape toward the second
+    #[must_use]
+    pub fn normal(&self) -> Vec3 {
+        self.normal
+    }
+
+    /// Set the friction coefficient of all the contacts
+    pub fn set_friction(&mut self, friction: f32) {
+        for contact in self.solver_contacts.iter_mut() {
+            contact.friction = friction;
+        }
+    }
+
+    /// Set the restitution coefficient of all the contacts
+    pub fn set_restitution(&mut self, restitution: f32) {
+        for contact in self.solver_contacts.iter_mut() {
+            contact.restitution = restitution;
+        }
+    }
+
+    /// Set the tangent velocity of all the contacts (e.g. for conveyor belts)
+    pub fn set_tangent_velocity(&mut self, velocity: Vec3) {
+        for contact in self.solver_contacts.iter_mut() {
+            contact.tangent_velocity = velocity.into_rapier();
+        }
+    }
+
+    /// Remove all the contacts, so that the collision shapes don't push each other
+    pub fn clear(&mut self) {
+        self.solver_contacts.clear();
+    }
+
+    /// Direct access to the rapier solver contacts, for advanced modifications
+    pub fn solver_contacts_mut(&mut self) -> &mut Vec<SolverContact> {
+        self.solver_contacts
+    }
+
+    /// Data persisted between physics steps for this pair of collision shapes
+    ///
+    /// It is zero the first time the pair is in contact.
+    pub fn user_data_mut(&mut self) -> &mut u32 {
+        self.user_data
+    }
+}
+
+/// Adapter calling the user-defined hooks with heron types
+pub(crate) struct HooksAdapter<'a>(&'a dyn PhysicsHooks);
+
+impl<'a> HooksAdapter<'a> {
+    pub(crate) fn new(resource: &'a PhysicsHooksResource) -> Self {
+        Self(&*resource.0)
+    }
+}
+
+impl<'a> pipeline::PhysicsHooks<RigidBodySet, ColliderSet> for HooksAdapter<'a> {
+    fn filter_contact_pair(
+        &self,
+        context: &pipeline::PairFilterContext<'_, RigidBodySet, ColliderSet>,
+    ) -> Option<SolverFlags> {
+        let pair = pair_context(
+            context.bodies,
+            context.colliders,
+            context.collider1,
+            context.collider2,
+            context.rigid_body1,
+            context.rigid_body2,
+        );
+        if self.0.filter_contact_pair(&pair) {
+            Some(SolverFlags::COMPUTE_IMPULSES)
+        } else {
+            None
+        }
+    }
+
+    fn filter_intersection_pair(
+        &self,
+        context: &pipeline::PairFilterContext<'_, RigidBodySet, ColliderSet>,
+    ) -> bool {
+        let pair = pair_context(
+            context.bodies,
+            context.colliders,
+            context.collider1,
+            context.collider2,
+            context.rigid_body1,
+            context.rigid_body2,
+        );
+        self.0.filter_intersection_pair(&pair)
+    }
+
+    fn modify_solver_contacts(
+        &self,
+        context: &mut pipeline::ContactModificationContext<'_, RigidBodySet, ColliderSet>,
+    ) {
+        let pair = pair_context(
+            context.bodies,
+            context.colliders,
+            context.collider1,
+            context.collider2,
+            context.rigid_body1,
+            context.rigid_body2,
+        );
+        let mut heron_context = ContactModificationContext {
+            pair,
+            normal: (*context.normal).into_bevy(),
+            solver_contacts: &mut *context.solver_contacts,
+            user_data: &mut *context.user_data,
+        };
+        self.0.modify_solver_contacts(&mut heron_context);
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn pair_context(
+    bodies: &RigidBodySet,
+    colliders: &ColliderSet,
+    collision_shape1: ColliderHandle,
+    collision_shape2: ColliderHandle,
+    rigid_body1: Option<RigidBodyHandle>,
+    rigid_body2: Option<RigidBodyHandle>,
+) -> PairFilterContext {
+    let collider_entity =
+        |handle: ColliderHandle| Entity::from_bits(colliders[handle].user_data as u64);
+    let body_entity = |handle: Option<RigidBodyHandle>| {
+        handle
+            .and_then(|handle| bodies.get(handle))
+            .map(|body| Entity::from_bits(body.user_data as u64))
+    };
+
+    PairFilterContext {
+        collision_shape1: collider_entity(collision_shape1),
+        collision_shape2: collider_entity(collision_shape2),
+        rigid_body1: body_entity(rigid_body1),
+        rigid_body2: body_entity(rigid_body2),
+    }
+}
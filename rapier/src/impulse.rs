@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+use heron_core::{utils::NearZero, Impulse};
+
+use crate::body::HandleMap;
+use crate::convert::IntoRapier;
+use crate::rapier::dynamics::RigidBodySet;
+use crate::rapier::math::{AngVector, Point, Vector};
+
+pub(crate) fn apply_impulses(
+    mut commands: Commands<'_, '_>,
+    mut bodies: ResMut<'_, RigidBodySet>,
+    handles: Res<'_, HandleMap>,
+    impulses: Query<'_, '_, (Entity, &Impulse), With<super::RigidBodyHandle>>,
+) {
+    for (entity, impulse) in impulses.iter() {
+        let handle = match handles.get(&entity) {
+            Some(handle) => *handle,
+            None => continue,
+        };
+
+        if let Some(body) = bodies.get_mut(handle) {
+            let wake_up = !impulse.is_near_zero();
+            let linear: Vector<f32> = impulse.linear.into_rapier();
+            let angular: AngVector<f32> = impulse.angular.into_rapier();
+
+            if let Some(point) = impulse.point {
+                let point: Point<f32> = point.into_rapier();
+                body.apply_impulse_at_point(linear, point, wake_up);
+            } else {
+                body.apply_impulse(linear, wake_up);
+            }
+            body.apply_torque_impulse(angular, wake_up);
+        }
+
+        commands.entity(entity).remove::<Impulse>();
+    }
+}
@@ -0,0 +1,121 @@
+use bevy::ecs::prelude::*;
+use bevy::math::prelude::*;
+use bevy::transform::prelude::*;
+
+use heron_core::{PhysicsSteps, RigidBody, TransformInterpolation};
+
+use crate::convert::IntoBevy;
+use crate::rapier::dynamics::RigidBodySet;
+
+/// Poses of the two last physics steps, used to interpolate the rendered transform
+#[derive(Debug, Component)]
+pub(crate) struct Poses {
+    previous: (Vec3, Quat),
+    current: (Vec3, Quat),
+    rendered: Option<(Vec3, Quat)>,
+}
+
+impl Poses {
+    /// Returns true if the given transform is the one that has been interpolated
+    ///
+    /// Such transform must not be fed back to the physics world
+    pub(crate) fn is_rendered(&self, transform: &GlobalTransform) -> bool {
+        self.rendered == Some((transform.translation, transform.rotation))
+    }
+}
+
+pub(crate) fn record_poses(
+    mut commands: Commands<'_, '_>,
+    bodies: Res<'_, RigidBodySet>,
+    handles: Res<'_, super::body::HandleMap>,
+    mut query: Query<
+        '_,
+        '_,
+        (Entity, Option<&mut Poses>),
+        (With<TransformInterpolation>, With<super::RigidBodyHandle>),
+    >,
+) {
+    for (entity, poses) in query.iter_mut() {
+        let pose: (Vec3, Quat) = match handles.get(&entity).and_then(|handle| bodies.get(*handle)) {
+            None => continue,
+            Some(body) => body.position().into_bevy(),
+        };
+
+        if let Some(mut poses) = poses {
+            poses.previous = poses.current;
+            poses.current = pose;
+        } else {
+            commands.entity(entity).insert(Poses {
+                previous: pose,
+                current: pose,
+                rendered: None,
+            });
+        }
+    }
+}
+
+pub(crate) fn remove_poses(
+    mut commands: Commands<'_, '_>,
+    removed: RemovedComponents<'_, TransformInterpolation>,
+    query: Query<'_, '_, Entity, With<Poses>>,
+) {
+    for entity in removed.iter() {
+        if query.get(entity).is_ok() {
+            commands.entity(entity).remove::<Poses>();
+        }
+    }
+}
+
+pub(crate) fn interpolate_transforms(
+    steps: Res<'_, PhysicsSteps>,
+    handles: Res<'_, super::body::HandleMap>,
+    mut query: Query<
+        '_,
+        '_,
+        (
+            Entity,
+            &TransformInterpolation,
+            &mut Poses,
+            Option<&mut Transform>,
+            &mut GlobalTransform,
+            Option<&RigidBody>,
+        ),
+    >,
+) {
+    let progress = match steps.step_progress() {
+        None => return,
+        Some(progress) => progress,
+    };
+
+    for (entity, mode, mut poses, local, mut global, body_type) in query.iter_mut() {
+        if !handles.contains_key(&entity)
+            || !body_type.copied().unwrap_or_default().can_have_velocity()
+        {
+            continue;
+        }
+
+        let t = match mode {
+            TransformInterpolation::Interpolate => progress,
+            TransformInterpolation::Extrapolate => 1.0 + progress,
+        };
+
+        let (previous_translation, previous_rotation) = poses.previous;
+        let (current_translation, current_rotation) = poses.current;
+
+        #[cfg(dim3)]
+        let translation = previous_translation.lerp(current_translation, t);
+        #[cfg(dim2)]
+        let mut translation = previous_translation.lerp(current_translation, t);
+
+        #[cfg(dim2)]
+        {
+            // In 2D, preserve the transform `z` component that may have been set by the user
+            translation.z = global.translation.z;
+        }
+
+        let rotation = previous_rotation.slerp(current_rotation, t);
+
+        super::body::set_bevy_transform(local, &mut global, translation, rotation);
+        poses.rendered = Some((translation, rotation));
+    }
+}
@@ -0,0 +1,158 @@
+use bevy::ecs::prelude::*;
+use bevy::log::prelude::*;
+use bevy::math::{Quat, Vec3};
+use fnv::FnvHashMap;
+
+use heron_core::{Joint, JointKind};
+
+use crate::convert::IntoRapier;
+use crate::nalgebra::Unit;
+#[cfg(dim3)]
+use crate::rapier::dynamics::RevoluteJoint;
+use crate::rapier::dynamics::{
+    BallJoint, FixedJoint, IslandManager, JointHandle, JointParams, JointSet, PrismaticJoint,
+    RigidBodySet,
+};
+use crate::rapier::math::{Point, Vector};
+
+pub(crate) type HandleMap = FnvHashMap<Entity, JointHandle>;
+
+pub(crate) fn create(
+    mut commands: Commands<'_, '_>,
+    mut joints: ResMut<'_, JointSet>,
+    mut handles: ResMut<'_, HandleMap>,
+    body_handles: Res<'_, crate::body::HandleMap>,
+    query: Query<'_, '_, (Entity, &Joint), Without<super::JointHandle>>,
+) {
+    for (entity, joint) in query.iter() {
+        if let (Some(body1), Some(body2)) = (
+            body_handles.get(&joint.body1),
+            body_handles.get(&joint.body2),
+        ) {
+            let handle = joints.insert(*body1, *body2, joint_params(joint));
+            handles.insert(entity, handle);
+            commands.entity(entity).insert(super::JointHandle(handle));
+        }
+    }
+}
+
+pub(crate) fn remove_invalids_after_components_removed(
+    mut commands: Commands<'_, '_>,
+    mut handles: ResMut<'_, HandleMap>,
+    mut joints: ResMut<'_, JointSet>,
+    mut islands: ResMut<'_, IslandManager>,
+    mut bodies: ResMut<'_, RigidBodySet>,
+    joints_removed: RemovedComponents<'_, Joint>,
+    joint_entities: Query<'_, '_, Entity, With<super::JointHandle>>,
+) {
+    for entity in joints_removed.iter() {
+        if let Some(handle) = handles.remove(&entity) {
+            joints.remove(handle, &mut islands, &mut *bodies, true);
+            if joint_entities.get(entity).is_ok() {
+                commands.entity(entity).remove::<super::JointHandle>();
+            }
+        }
+    }
+}
+
+pub(crate) fn remove_invalids_after_component_changed(
+    mut commands: Commands<'_, '_>,
+    mut handles: ResMut<'_, HandleMap>,
+    mut joints: ResMut<'_, JointSet>,
+    mut islands: ResMut<'_, IslandManager>,
+    mut bodies: ResMut<'_, RigidBodySet>,
+    changed: Query<'_, '_, Entity, (Changed<Joint>, With<super::JointHandle>)>,
+) {
+    for entity in changed.iter() {
+        if let Some(handle) = handles.remove(&entity) {
+            joints.remove(handle, &mut islands, &mut *bodies, true);
+            commands.entity(entity).remove::<super::JointHandle>();
+        }
+    }
+}
+
+/// Removes the joints for which one of the rigid bodies no longer exists
+///
+/// Rapier already removes the joints attached to a removed rigid body. But the handles must be
+/// removed as well, so that the joint is created again if the rigid body is recreated.
+pub(crate) fn remove_dangling_joints(
+    mut commands: Commands<'_, '_>,
+    mut handles: ResMut<'_, HandleMap>,
+    mut joints: ResMut<'_, JointSet>,
+    mut islands: ResMut<'_, IslandManager>,
+    mut bodies: ResMut<'_, RigidBodySet>,
+    body_handles: Res<'_, crate::body::HandleMap>,
+    query: Query<'_, '_, (Entity, &Joint), With<super::JointHandle>>,
+) {
+    for (entity, joint) in query.iter() {
+        if body_handles.contains_key(&joint.body1) && body_handles.contains_key(&joint.body2) {
+            continue;
+        }
+
+        if let Some(handle) = handles.remove(&entity) {
+            joints.remove(handle, &mut islands, &mut *bodies, true);
+            commands.entity(entity).remove::<super::JointHandle>();
+        }
+    }
+}
+
+fn joint_params(joint: &Joint) -> JointParams {
+    let anchor1: Point<f32> = joint.anchor1.into_rapier();
+    let anchor2: Point<f32> = joint.anchor2.into_rapier();
+
+    match joint.kind {
+        JointKind::Fixed => FixedJoint::new(
+            (joint.anchor1, Quat::IDENTITY).into_rapier(),
+            (joint.anchor2, Quat::IDENTITY).into_rapier(),
+        )
+        .into(),
+        JointKind::Ball => BallJoint::new(anchor1, anchor2).into(),
+        #[cfg(dim2)]
+        JointKind::Revolute { .. } => BallJoint::new(anchor1, anchor2).into(),
+        #[cfg(dim3)]
+        JointKind::Revolute { axis1, axis2 } => {
+            RevoluteJoint::new(anchor1, axis(axis1), anchor2, axis(axis2)).into()
+        }
+        #[cfg(dim2)]
+        JointKind::Prismatic { axis1, axis2 } => {
+            PrismaticJoint::new(anchor1, axis(axis1), anchor2, axis(axis2)).into()
+        }
+        #[cfg(dim3)]
+        JointKind::Prismatic { axis1, axis2 } => {
+            let (axis1, axis2) = (axis(axis1), axis(axis2));
+            PrismaticJoint::new(
+                anchor1,
+                axis1,
+                tangent(axis1),
+                anchor2,
+                axis2,
+                tangent(axis2),
+            )
+            .into()
+        }
+    }
+}
+
+/// Returns the normalized axis, or the `x` axis if it cannot be normalized (zero-length or not
+/// finite)
+fn axis(axis: Vec3) -> Unit<Vector<f32>> {
+    let vector: Vector<f32> = axis.into_rapier();
+    match Unit::try_new(vector, f32::EPSILON) {
+        Some(unit) if unit.iter().all(|x| x.is_finite()) => unit,
+        _ => {
+            warn!("Invalid joint axis {:?}, the x axis is used instead", axis);
+            Vector::x_axis()
+        }
+    }
+}
+
+/// Returns an arbitrary vector orthogonal to the given axis
+#[inline]
+#[cfg(dim3)]
+fn tangent(axis: Unit<Vector<f32>>) -> Vector<f32> {
+    if axis.x.abs() < 0.9 {
+        axis.cross(&Vector::x())
+    } else {
+        axis.cross(&Vector::y())
+    }
+}
@@ -28,8 +28,18 @@ pub(crate) use rapier2d as rapier;
 #[cfg(dim3)]
 pub(crate) use rapier3d as rapier;
 
-use heron_core::{CollisionEvent, PhysicsSystem};
+use heron_core::{
+    CollisionContactEvent, CollisionEvent, CollisionShapeErrorEvent, PhysicsSteps, PhysicsSystem,
+    SleepEvent,
+};
+pub use hooks::{
+    ContactModificationContext, PairFilterContext, PhysicsHooks, PhysicsHooksResource,
+};
 pub use pipeline::{PhysicsWorld, RayCastInfo, ShapeCastCollisionInfo, ShapeCastCollisionType};
+pub use shape::HandleMap as ColliderHandleMap;
+#[cfg(snapshot)]
+pub use snapshot::PhysicsSnapshot;
+pub use world::{PhysicsWorldSettings, PhysicsWorlds};
 
 use crate::rapier::dynamics::{
     self, CCDSolver, IntegrationParameters, IslandManager, JointSet, RigidBodySet,
@@ -40,11 +50,27 @@ use crate::rapier::pipeline::{PhysicsPipeline, QueryPipeline};
 
 mod acceleration;
 mod body;
+mod ccd;
+mod character_controller;
+mod collisions;
+mod constraints;
 pub mod convert;
 mod damping;
+mod gravity;
+mod hooks;
+mod impulse;
+mod interpolation;
+mod joint;
+mod mass;
+#[cfg(feature = "collision-from-mesh")]
+mod mesh;
 mod pipeline;
 mod shape;
+mod sleep;
+#[cfg(snapshot)]
+mod snapshot;
 mod velocity;
+mod world;
 
 /// Plugin that enables collision detection and physics behavior, powered by rapier.
 #[must_use]
@@ -65,9 +91,18 @@ pub struct RigidBodyHandle(dynamics::RigidBodyHandle);
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Component)]
 pub struct ColliderHandle(geometry::ColliderHandle);
 
+/// Component that holds a reference to the rapier joint
+///
+/// It is automatically inserted and removed by heron.
+/// It is only useful for advanced, direct access to the rapier world
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Component)]
+pub struct JointHandle(dynamics::JointHandle);
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, SystemLabel)]
 enum InternalSystem {
     TransformPropagation,
+    SleepEvents,
+    TranslationConstraints,
 }
 
 impl Plugin for RapierPlugin {
@@ -76,8 +111,14 @@ impl Plugin for RapierPlugin {
             .init_resource::<PhysicsPipeline>()
             .init_resource::<body::HandleMap>()
             .init_resource::<shape::HandleMap>()
+            .init_resource::<joint::HandleMap>()
             .init_resource::<IntegrationParameters>()
+            .init_resource::<world::ActiveWorld>()
+            .init_resource::<PhysicsWorlds>()
             .add_event::<CollisionEvent>()
+            .add_event::<CollisionContactEvent>()
+            .add_event::<SleepEvent>()
+            .add_event::<CollisionShapeErrorEvent>()
             .insert_resource(BroadPhase::new())
             .insert_resource(NarrowPhase::new())
             .insert_resource(RigidBodySet::new())
@@ -93,16 +134,60 @@ impl Plugin for RapierPlugin {
                     .add_stage("heron-create-new-bodies", body_update_stage())
                     .add_stage("heron-create-new-colliders", create_collider_stage())
             })
-            .add_system_set_to_stage(CoreStage::PostUpdate, step_systems());
+            .add_system_set_to_stage(CoreStage::PostUpdate, step_systems())
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                interpolation::interpolate_transforms
+                    .system()
+                    .after(PhysicsSystem::TransformUpdate),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                world::step_additional_worlds.exclusive_system().at_end(),
+            )
+            .add_system_to_stage(CoreStage::Last, collisions::fill_event_queues.system())
+            .add_system_to_stage(CoreStage::Last, body::warn_world_changes.system());
+
+        #[cfg(feature = "collision-from-mesh")]
+        app.add_system_to_stage(CoreStage::PreUpdate, mesh::update_collision_shapes.system());
     }
 }
 
+/// Schedule stepping an additional physics world
+///
+/// It contains the same systems as the ones added to the app for the default world.
+fn world_schedule() -> Schedule {
+    Schedule::default()
+        .with_stage(
+            "heron-update-steps",
+            SystemStage::single_threaded().with_system(PhysicsSteps::update.system()),
+        )
+        .with_stage("heron-remove", removal_stage())
+        .with_stage("heron-update-rapier-world", update_rapier_world_stage())
+        .with_stage("heron-create-new-bodies", body_update_stage())
+        .with_stage("heron-create-new-colliders", create_collider_stage())
+        .with_stage(
+            "heron-step",
+            SystemStage::parallel()
+                .with_system_set(step_systems())
+                .with_system(
+                    interpolation::interpolate_transforms
+                        .system()
+                        .after(PhysicsSystem::TransformUpdate),
+                ),
+        )
+}
+
 fn removal_stage() -> SystemStage {
     SystemStage::single_threaded()
         .with_system(body::remove_invalids_after_components_removed.system())
         .with_system(shape::remove_invalids_after_components_removed.system())
         .with_system(body::remove_invalids_after_component_changed.system())
         .with_system(shape::remove_invalids_after_component_changed.system())
+        .with_system(shape::remove_invalid_flags.system())
+        .with_system(joint::remove_invalids_after_components_removed.system())
+        .with_system(joint::remove_invalids_after_component_changed.system())
+        .with_system(interpolation::remove_poses.system())
 }
 
 fn update_rapier_world_stage() -> SystemStage {
@@ -112,6 +197,11 @@ fn update_rapier_world_stage() -> SystemStage {
                 .system()
                 .label(InternalSystem::TransformPropagation),
         )
+        .with_system(
+            character_controller::update_character_controllers
+                .system()
+                .before(InternalSystem::TransformPropagation),
+        )
         .with_system(
             body::update_rapier_position
                 .system()
@@ -121,11 +211,29 @@ fn update_rapier_world_stage() -> SystemStage {
         .with_system(acceleration::update_rapier_force_and_torque.system())
         .with_system(damping::update_rapier_damping.system())
         .with_system(damping::reset_rapier_damping.system())
+        .with_system(mass::update_rapier_mass_properties.system())
+        .with_system(sleep::update_rapier_sleeping.system())
+        .with_system(sleep::reset_rapier_sleeping.system())
+        .with_system(ccd::enable_ccd.system())
+        .with_system(ccd::disable_ccd.system())
+        .with_system(gravity::update_gravity_scale.system())
+        .with_system(gravity::reset_gravity_scale.system())
+        .with_system(
+            shape::remove_invalids_after_scale_changed
+                .system()
+                .after(InternalSystem::TransformPropagation),
+        )
         .with_system(shape::update_position.system())
+        .with_system(shape::update_materials.system())
         .with_system(shape::update_collision_groups.system())
         .with_system(shape::update_sensor_flag.system())
         .with_system(shape::remove_sensor_flag.system())
         .with_system(shape::reset_collision_groups.system())
+        .with_system(shape::update_active_hooks.system())
+        .with_system(shape::reset_active_hooks.system())
+        .with_system(shape::update_active_events.system())
+        .with_system(shape::reset_active_events.system())
+        .with_system(joint::remove_dangling_joints.system())
 }
 
 fn body_update_stage() -> SystemStage {
@@ -138,6 +246,7 @@ fn create_collider_stage() -> SystemStage {
     SystemStage::single_threaded()
         .with_run_criteria(heron_core::should_run.system())
         .with_system(shape::create.system())
+        .with_system(joint::create.system())
 }
 
 fn step_systems() -> SystemSet {
@@ -148,6 +257,16 @@ fn step_systems() -> SystemSet {
                 .system()
                 .before(PhysicsSystem::Events),
         )
+        .with_system(
+            impulse::apply_impulses
+                .system()
+                .before(PhysicsSystem::Events),
+        )
+        .with_system(
+            gravity::apply_gravity_zones
+                .system()
+                .before(PhysicsSystem::Events),
+        )
         .with_system(pipeline::step.system().label(PhysicsSystem::Events))
         .with_system(
             body::update_bevy_transform
@@ -167,4 +286,39 @@ fn step_systems() -> SystemSet {
                 .after(PhysicsSystem::Events)
                 .after(PhysicsSystem::VelocityUpdate),
         )
+        .with_system(
+            constraints::apply_translation_constraints
+                .system()
+                .label(InternalSystem::TranslationConstraints)
+                .after(PhysicsSystem::Events)
+                .before(PhysicsSystem::TransformUpdate)
+                .before(PhysicsSystem::VelocityUpdate),
+        )
+        .with_system(
+            interpolation::record_poses
+                .system()
+                .after(InternalSystem::TranslationConstraints)
+                .before(PhysicsSystem::TransformUpdate),
+        )
+        .with_system(
+            sleep::fire_sleep_events
+                .system()
+                .label(InternalSystem::SleepEvents)
+                .after(PhysicsSystem::Events),
+        )
+        .with_system(
+            sleep::update_sleeping_component
+                .system()
+                .after(InternalSystem::SleepEvents),
+        )
+        .with_system(
+            mass::update_computed_mass_properties
+                .system()
+                .after(PhysicsSystem::Events),
+        )
+        .with_system(
+            collisions::update_collisions
+                .system()
+                .after(PhysicsSystem::Events),
+        )
 }
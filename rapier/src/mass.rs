@@ -0,0 +1,99 @@
+use bevy::ecs::prelude::*;
+use bevy::math::Vec3;
+
+use heron_core::{ComputedMassProperties, MassProperties};
+
+use crate::body::HandleMap;
+use crate::convert::{IntoBevy, IntoRapier};
+use crate::rapier::dynamics::{self, RigidBodySet};
+
+pub(crate) fn update_rapier_mass_properties(
+    mut bodies: ResMut<'_, RigidBodySet>,
+    handles: Res<'_, HandleMap>,
+    query: Query<
+        '_,
+        '_,
+        (Entity, &MassProperties),
+        (Changed<MassProperties>, With<super::RigidBodyHandle>),
+    >,
+) {
+    for (entity, mass_properties) in query.iter() {
+        if let Some(body) = handles
+            .get(&entity)
+            .and_then(|handle| bodies.get_mut(*handle))
+        {
+            body.set_mass_properties(to_rapier(mass_properties), true);
+        }
+    }
+}
+
+pub(crate) fn update_computed_mass_properties(
+    bodies: Res<'_, RigidBodySet>,
+    handles: Res<'_, HandleMap>,
+    mut query: Query<'_, '_, (Entity, &mut ComputedMassProperties), With<super::RigidBodyHandle>>,
+) {
+    for (entity, mut computed) in query.iter_mut() {
+        if let Some(body) = handles.get(&entity).and_then(|handle| bodies.get(*handle)) {
+            let properties = body.mass_properties();
+
+            #[cfg(dim2)]
+            let local_center_of_mass = properties.local_com.into_bevy().extend(0.0);
+            #[cfg(dim3)]
+            let local_center_of_mass = properties.local_com.into_bevy();
+
+            let new_value = ComputedMassProperties::new(
+                body.mass(),
+                local_center_of_mass,
+                principal_inertia(properties),
+            );
+
+            if *computed != new_value {
+                *computed = new_value;
+            }
+        }
+    }
+}
+
+pub(crate) fn to_rapier(properties: &MassProperties) -> dynamics::MassProperties {
+    #[cfg(dim2)]
+    let principal_inertia = properties.principal_inertia.z;
+    #[cfg(dim3)]
+    let principal_inertia = properties.principal_inertia.into_rapier();
+
+    dynamics::MassProperties::new(
+        properties.local_center_of_mass.into_rapier(),
+        properties.mass,
+        principal_inertia,
+    )
+}
+
+/// Rapier stores the inverse square root of the inertia, which is zero along locked or infinite axes
+#[cfg(dim2)]
+fn principal_inertia(properties: &dynamics::MassProperties) -> Vec3 {
+    Vec3::Z * inverse_sqrt_to_inertia(properties.inv_principal_inertia_sqrt)
+}
+
+/// Rapier stores the inverse square root of the inertia, which is zero along locked or infinite axes
+///
+/// The principal axes may be rotated relative to the rigid body, in which case the inertia is
+/// expressed in the local space of the rigid body by only keeping its diagonal.
+#[cfg(dim3)]
+fn principal_inertia(properties: &dynamics::MassProperties) -> Vec3 {
+    let inertia = properties.inv_principal_inertia_sqrt.into_bevy();
+    let inertia = Vec3::new(
+        inverse_sqrt_to_inertia(inertia.x),
+        inverse_sqrt_to_inertia(inertia.y),
+        inverse_sqrt_to_inertia(inertia.z),
+    );
+    let frame = bevy::math::Mat3::from_quat(properties.principal_inertia_local_frame.into_bevy());
+    let local = frame * bevy::math::Mat3::from_diagonal(inertia) * frame.transpose();
+    Vec3::new(local.x_axis.x, local.y_axis.y, local.z_axis.z)
+}
+
+fn inverse_sqrt_to_inertia(value: f32) -> f32 {
+    if value > 0.0 {
+        1.0 / (value * value)
+    } else {
+        0.0
+    }
+}
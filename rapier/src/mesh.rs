@@ -0,0 +1,219 @@
+use bevy::ecs::event::{Events, ManualEventReader};
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, VertexAttributeValues};
+use bevy::render::render_resource::PrimitiveTopology;
+use fnv::FnvHashSet;
+
+use heron_core::{CollisionShape, MeshCollisionShape, MeshShapeKind};
+
+#[cfg(dim3)]
+use heron_core::CompoundShapeChild;
+
+#[cfg(dim3)]
+use crate::convert::{IntoBevy, IntoRapier};
+#[cfg(dim3)]
+use crate::rapier::geometry::SharedShape;
+#[cfg(dim3)]
+use crate::rapier::math::Point;
+
+/// Does nothing if the mesh assets are not available (e.g. when the `AssetPlugin` is not added)
+pub(crate) fn update_collision_shapes(
+    mut commands: Commands<'_, '_>,
+    meshes: Option<Res<'_, Assets<Mesh>>>,
+    events: Option<Res<'_, Events<AssetEvent<Mesh>>>>,
+    mut reader: Local<'_, ManualEventReader<AssetEvent<Mesh>>>,
+    query: Query<
+        '_,
+        '_,
+        (
+            Entity,
+            &MeshCollisionShape,
+            ChangeTrackers<MeshCollisionShape>,
+        ),
+    >,
+) {
+    let (meshes, events) = match (meshes, events) {
+        (Some(meshes), Some(events)) => (meshes, events),
+        _ => return,
+    };
+
+    let updated_meshes: FnvHashSet<Handle<Mesh>> = reader
+        .iter(&events)
+        .filter_map(|event| match event {
+            AssetEvent::Created { handle } | AssetEvent::Modified { handle } => {
+                Some(handle.clone_weak())
+            }
+            AssetEvent::Removed { .. } => None,
+        })
+        .collect();
+
+    for (entity, mesh_shape, tracker) in query.iter() {
+        if !tracker.is_changed() && !updated_meshes.contains(&mesh_shape.mesh) {
+            continue;
+        }
+
+        // If the mesh isn't loaded yet, the shape is built once its `Created` event is received
+        if let Some(shape) = meshes
+            .get(&mesh_shape.mesh)
+            .and_then(|mesh| build_shape(mesh, mesh_shape.kind))
+        {
+            commands.entity(entity).insert(shape);
+        }
+    }
+}
+
+fn build_shape(mesh: &Mesh, kind: MeshShapeKind) -> Option<CollisionShape> {
+    let vertices = vertices(mesh)?;
+    match kind {
+        MeshShapeKind::ConvexHull => Some(CollisionShape::ConvexHull {
+            points: vertices,
+            border_radius: None,
+        }),
+        MeshShapeKind::TriMesh => Some(CollisionShape::TriMesh {
+            indices: triangles(mesh)?,
+            vertices,
+        }),
+        #[cfg(dim3)]
+        MeshShapeKind::ConvexDecomposition => {
+            Some(convex_decomposition(&vertices, &triangles(mesh)?))
+        }
+    }
+}
+
+fn vertices(mesh: &Mesh) -> Option<Vec<Vec3>> {
+    if let Some(VertexAttributeValues::Float32x3(positions)) =
+        mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+    {
+        Some(positions.iter().copied().map(Vec3::from).collect())
+    } else {
+        warn!("Cannot build a collision shape from a mesh without 3d vertex positions");
+        None
+    }
+}
+
+fn triangles(mesh: &Mesh) -> Option<Vec<[u32; 3]>> {
+    if !matches!(mesh.primitive_topology(), PrimitiveTopology::TriangleList) {
+        warn!(
+            "Cannot build a triangle-based collision shape from a mesh with the {:?} topology",
+            mesh.primitive_topology()
+        );
+        return None;
+    }
+
+    let indices: Vec<u32> = match mesh.indices() {
+        Some(Indices::U16(indices)) => indices.iter().copied().map(u32::from).collect(),
+        Some(Indices::U32(indices)) => indices.clone(),
+        #[allow(clippy::cast_possible_truncation)]
+        None => (0..mesh.count_vertices() as u32).collect(),
+    };
+
+    Some(
+        indices
+            .chunks_exact(3)
+            .map(|triangle| [triangle[0], triangle[1], triangle[2]])
+            .collect(),
+    )
+}
+
+#[cfg(dim3)]
+fn convex_decomposition(vertices: &[Vec3], indices: &[[u32; 3]]) -> CollisionShape {
+    let vertices: Vec<Point<f32>> = vertices.into_rapier();
+    let decomposition = SharedShape::convex_decomposition(&vertices, indices);
+    let shapes = decomposition
+        .as_compound()
+        .map(|compound| {
+            compound
+                .shapes()
+                .iter()
+                .filter_map(|(position, part)| {
+                    let (translation, rotation) = position.into_bevy();
+                    let points = part.as_convex_polyhedron()?.points();
+                    Some(CompoundShapeChild {
+                        translation,
+                        rotation,
+                        shape: CollisionShape::ConvexHull {
+                            points: points.iter().map(|point| point.into_bevy()).collect(),
+                            border_radius: None,
+                        },
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    CollisionShape::Compound { shapes }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::render::mesh::shape;
+
+    use super::*;
+
+    #[test]
+    fn build_convex_hull() {
+        let mesh = Mesh::from(shape::Cube::new(2.0));
+
+        let shape = build_shape(&mesh, MeshShapeKind::ConvexHull);
+
+        match shape {
+            Some(CollisionShape::ConvexHull { points, .. }) => {
+                assert_eq!(points.len(), mesh.count_vertices());
+            }
+            _ => panic!("Created shape was not a convex hull"),
+        }
+    }
+
+    #[test]
+    fn build_trimesh() {
+        let mesh = Mesh::from(shape::Cube::new(2.0));
+
+        let shape = build_shape(&mesh, MeshShapeKind::TriMesh);
+
+        match shape {
+            Some(CollisionShape::TriMesh { indices, .. }) => assert_eq!(indices.len(), 12),
+            _ => panic!("Created shape was not a triangle mesh"),
+        }
+    }
+
+    #[test]
+    fn build_trimesh_without_indices() {
+        let mut mesh = Mesh::from(shape::Cube::new(2.0));
+        mesh.duplicate_vertices();
+
+        let shape = build_shape(&mesh, MeshShapeKind::TriMesh);
+
+        match shape {
+            Some(CollisionShape::TriMesh { indices, .. }) => {
+                assert_eq!(indices.len(), 12);
+                assert_eq!(indices[1], [3, 4, 5]);
+            }
+            _ => panic!("Created shape was not a triangle mesh"),
+        }
+    }
+
+    #[test]
+    fn cannot_build_trimesh_from_lines() {
+        let mut mesh = Mesh::new(PrimitiveTopology::LineList);
+        mesh.set_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0]],
+        );
+
+        assert!(build_shape(&mesh, MeshShapeKind::TriMesh).is_none());
+        assert!(build_shape(&mesh, MeshShapeKind::ConvexHull).is_some());
+    }
+
+    #[test]
+    #[cfg(dim3)]
+    fn build_convex_decomposition() {
+        let mesh = Mesh::from(shape::Cube::new(2.0));
+
+        let shape = build_shape(&mesh, MeshShapeKind::ConvexDecomposition);
+
+        match shape {
+            Some(CollisionShape::Compound { shapes }) => assert!(!shapes.is_empty()),
+            _ => panic!("Created shape was not a compound"),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use std::cmp::Ordering;
 use std::marker::PhantomData;
 
 use bevy::app::Events;
@@ -9,21 +10,23 @@ use bevy::math::Vec3;
 use crossbeam::channel::{Receiver, Sender};
 
 use heron_core::{
-    CollisionData, CollisionEvent, CollisionLayers, CollisionShape, Gravity, PhysicsStepDuration,
-    PhysicsSteps, PhysicsTime,
+    CollisionContactEvent, CollisionData, CollisionEvent, CollisionLayers, CollisionShape,
+    ContactPoint, Gravity, PhysicsStepDuration, PhysicsSteps, PhysicsTime,
 };
 pub use physics_world::PhysicsWorld;
 
 use crate::convert::{IntoBevy, IntoRapier};
+use crate::hooks::{HooksAdapter, PhysicsHooksResource};
 use crate::rapier::dynamics::{
     CCDSolver, IntegrationParameters, IslandManager, JointSet, RigidBodySet,
 };
 use crate::rapier::geometry::{
-    BroadPhase, ColliderHandle, ColliderSet, ContactEvent, InteractionGroups, IntersectionEvent,
-    NarrowPhase,
+    BroadPhase, ColliderHandle, ColliderSet, ContactEvent, ContactPair, InteractionGroups,
+    IntersectionEvent, NarrowPhase,
 };
+use crate::rapier::math::{Isometry, Point, Vector};
 use crate::rapier::parry::query::{Ray, TOIStatus};
-use crate::rapier::pipeline::{EventHandler, PhysicsPipeline, QueryPipeline};
+use crate::rapier::pipeline::{EventHandler, PhysicsHooks, PhysicsPipeline, QueryPipeline};
 use crate::shape::ColliderFactory;
 
 // We have to make a module here so that we can allow missing docs on the structs generated by the
@@ -39,6 +42,8 @@ mod physics_world {
     ///
     /// See the [`ray_casting`](heron::ray_casting)
     /// example for a detailed usage example.
+    ///
+    /// It only queries the default physics world (see [`PhysicsWorlds`](crate::PhysicsWorlds)).
     #[derive(SystemParam)]
     pub struct PhysicsWorld<'w, 's> {
         query_pipeline: ResMut<'w, QueryPipeline>,
@@ -89,7 +94,6 @@ mod physics_world {
 
         /// Non-public implementation of `ray_cast`
         #[must_use]
-        #[allow(clippy::cast_possible_truncation)]
         fn ray_cast_internal(
             &self,
             start: Vec3,
@@ -110,32 +114,135 @@ mod physics_world {
                     memberships: layers.groups_bits(),
                     filter: layers.masks_bits(),
                 },
-                // Map filter to one that takes a collider handle and returns a bool
-                filter
-                    .map(|filter| {
-                        move |handle: ColliderHandle| -> bool {
-                            self.colliders
-                                .get(handle)
-                                .map(|collider| Entity::from_bits(collider.user_data as u64))
-                                .map_or(false, filter)
-                        }
-                    })
-                    .as_ref()
-                    .map(|x| x as &dyn Fn(ColliderHandle) -> bool),
+                Some(&self.collider_filter(filter)),
             );
 
             result.map(|(collider_handle, intersection)| {
                 Some(RayCastInfo {
                     collision_point: start + direction * intersection.toi,
-                    entity: self
-                        .colliders
-                        .get(collider_handle)
-                        .map(|collider| Entity::from_bits(collider.user_data as u64))?,
+                    entity: self.entity(collider_handle)?,
                     normal: intersection.normal.into_bevy(),
                 })
             })?
         }
 
+        /// Cast a ray and get every collision shape entity, point, and normal at which it collided,
+        /// sorted by distance from the `start` point
+        ///
+        /// Takes the same arguments as [`ray_cast`](Self::ray_cast).
+        #[must_use]
+        pub fn ray_cast_all(&self, start: Vec3, ray: Vec3, solid: bool) -> Vec<RayCastInfo> {
+            self.ray_cast_all_internal(start, ray, solid, CollisionLayers::default(), None)
+        }
+
+        /// Cast a ray with extra filters and get every collision, sorted by distance from the
+        /// `start` point
+        ///
+        /// Takes the same arguments as [`ray_cast_with_filter`](Self::ray_cast_with_filter).
+        #[must_use]
+        pub fn ray_cast_all_with_filter<F>(
+            &self,
+            start: Vec3,
+            ray: Vec3,
+            solid: bool,
+            layers: CollisionLayers,
+            filter: F,
+        ) -> Vec<RayCastInfo>
+        where
+            F: Fn(Entity) -> bool,
+        {
+            self.ray_cast_all_internal(start, ray, solid, layers, Some(&filter))
+        }
+
+        /// Cast a ray with extra filters and call `callback` for each collision
+        ///
+        /// Takes the same arguments as [`ray_cast_with_filter`](Self::ray_cast_with_filter), plus:
+        ///
+        /// - `callback`: A closure called for each collision. It should return `false` to stop
+        ///   the ray cast, or `true` to continue with the next collision.
+        ///
+        /// Note that the collisions are **not** sorted by distance.
+        /// Use [`ray_cast_all_with_filter`](Self::ray_cast_all_with_filter) if the order matters.
+        pub fn ray_cast_with_callback<F, C>(
+            &self,
+            start: Vec3,
+            ray: Vec3,
+            solid: bool,
+            layers: CollisionLayers,
+            filter: F,
+            mut callback: C,
+        ) where
+            F: Fn(Entity) -> bool,
+            C: FnMut(RayCastInfo) -> bool,
+        {
+            self.ray_cast_with_callback_internal(
+                start,
+                ray,
+                solid,
+                layers,
+                Some(&filter),
+                &mut callback,
+            );
+        }
+
+        #[must_use]
+        fn ray_cast_all_internal(
+            &self,
+            start: Vec3,
+            ray: Vec3,
+            solid: bool,
+            layers: CollisionLayers,
+            filter: Option<&dyn Fn(Entity) -> bool>,
+        ) -> Vec<RayCastInfo> {
+            let mut result = Vec::new();
+            self.ray_cast_with_callback_internal(start, ray, solid, layers, filter, &mut |info| {
+                result.push(info);
+                true
+            });
+            result.sort_by(|info1, info2| {
+                info1
+                    .collision_point
+                    .distance_squared(start)
+                    .partial_cmp(&info2.collision_point.distance_squared(start))
+                    .unwrap_or(Ordering::Equal)
+            });
+            result
+        }
+
+        fn ray_cast_with_callback_internal(
+            &self,
+            start: Vec3,
+            ray: Vec3,
+            solid: bool,
+            layers: CollisionLayers,
+            filter: Option<&dyn Fn(Entity) -> bool>,
+            callback: &mut dyn FnMut(RayCastInfo) -> bool,
+        ) {
+            let direction = match ray.try_normalize() {
+                Some(direction) => direction,
+                None => return,
+            };
+            let rapier_ray = Ray::new(start.into_rapier(), direction.into_rapier());
+
+            self.query_pipeline.intersections_with_ray(
+                &*self.colliders,
+                &rapier_ray,
+                ray.length(),
+                solid,
+                layers.into_rapier(),
+                Some(&self.collider_filter(filter)),
+                |collider_handle, intersection| {
+                    self.entity(collider_handle).map_or(true, |entity| {
+                        callback(RayCastInfo {
+                            collision_point: start + direction * intersection.toi,
+                            entity,
+                            normal: intersection.normal.into_bevy(),
+                        })
+                    })
+                },
+            );
+        }
+
         /// Cast a shape and get the collision shape entity, point, and normal at which it collided, if
         /// any
         ///
@@ -144,6 +251,8 @@ mod physics_world {
         /// - `rotation`: The rotation of the collision shape
         /// - `end_posiion`: The end position of the shape cast
         ///
+        /// Returns `None` if the shape is invalid (see [`heron_core::CollisionShapeError`]).
+        ///
         /// # Panics
         ///
         /// This will panic if the start position and end position are the same.
@@ -202,7 +311,6 @@ mod physics_world {
         }
 
         #[must_use]
-        #[allow(clippy::cast_possible_truncation)]
         fn shape_cast_internal(
             &self,
             shape: &CollisionShape,
@@ -213,7 +321,7 @@ mod physics_world {
             filter: Option<&dyn Fn(Entity) -> bool>,
         ) -> Option<ShapeCastInfo> {
             let direction = ray.try_normalize()?;
-            let collider = shape.collider_builder().build();
+            let collider = shape.collider_builder().ok()?.build();
 
             let result = self.query_pipeline.cast_shape(
                 &*self.colliders,
@@ -225,18 +333,7 @@ mod physics_world {
                     memberships: layers.groups_bits(),
                     filter: layers.masks_bits(),
                 },
-                // Map filter to one that takes a collider handle and returns a bool
-                filter
-                    .map(|filter| {
-                        move |handle: ColliderHandle| -> bool {
-                            self.colliders
-                                .get(handle)
-                                .map(|collider| Entity::from_bits(collider.user_data as u64))
-                                .map_or(false, filter)
-                        }
-                    })
-                    .as_ref()
-                    .map(|x| x as &dyn Fn(ColliderHandle) -> bool),
+                Some(&self.collider_filter(filter)),
             );
 
             result.map(|(collider_handle, toi)| {
@@ -271,14 +368,218 @@ mod physics_world {
                 };
 
                 Some(ShapeCastInfo {
-                    entity: self
-                        .colliders
-                        .get(collider_handle)
-                        .map(|collider| Entity::from_bits(collider.user_data as u64))?,
+                    entity: self.entity(collider_handle)?,
                     collision_type,
                 })
             })?
         }
+
+        /// Get every collision shape entity containing the given point
+        ///
+        /// - `point`: The point to test, in world space
+        #[must_use]
+        pub fn intersections_with_point(&self, point: Vec3) -> Vec<Entity> {
+            self.intersections_with_point_internal(point, CollisionLayers::default(), None)
+        }
+
+        /// Get every collision shape entity containing the given point, with extra filters
+        ///
+        /// Behaves the same as [`intersections_with_point`](Self::intersections_with_point) but
+        /// takes extra arguments for filtering results:
+        ///
+        /// - `layers`: The [`CollisionLayers`] to considered for collisions, allowing for coarse
+        ///   filtering of collisions.
+        /// - `filter`: A closure taking an [`Entity`] and returning `true` if the entity should be
+        ///   considered for collisions, allowing for fine-grained, per-entity filtering of
+        ///   collisions.
+        #[must_use]
+        pub fn intersections_with_point_with_filter<F>(
+            &self,
+            point: Vec3,
+            layers: CollisionLayers,
+            filter: F,
+        ) -> Vec<Entity>
+        where
+            F: Fn(Entity) -> bool,
+        {
+            self.intersections_with_point_internal(point, layers, Some(&filter))
+        }
+
+        #[must_use]
+        fn intersections_with_point_internal(
+            &self,
+            point: Vec3,
+            layers: CollisionLayers,
+            filter: Option<&dyn Fn(Entity) -> bool>,
+        ) -> Vec<Entity> {
+            let mut entities = Vec::new();
+
+            self.query_pipeline.intersections_with_point(
+                &*self.colliders,
+                &point.into_rapier(),
+                layers.into_rapier(),
+                Some(&self.collider_filter(filter)),
+                |handle| {
+                    entities.extend(self.entity(handle));
+                    true
+                },
+            );
+
+            entities
+        }
+
+        /// Get every collision shape entity overlapping the given axis-aligned box
+        ///
+        /// - `min`: The corner of the box with the lowest coordinates, in world space
+        /// - `max`: The corner of the box with the highest coordinates, in world space
+        ///
+        /// In 2d, the `z` coordinates are ignored.
+        #[must_use]
+        pub fn intersections_with_aabb(&self, min: Vec3, max: Vec3) -> Vec<Entity> {
+            self.intersections_with_aabb_internal(min, max, CollisionLayers::default(), None)
+        }
+
+        /// Get every collision shape entity overlapping the given axis-aligned box, with extra
+        /// filters
+        ///
+        /// Behaves the same as [`intersections_with_aabb`](Self::intersections_with_aabb) but
+        /// takes extra arguments for filtering results:
+        ///
+        /// - `layers`: The [`CollisionLayers`] to considered for collisions, allowing for coarse
+        ///   filtering of collisions.
+        /// - `filter`: A closure taking an [`Entity`] and returning `true` if the entity should be
+        ///   considered for collisions, allowing for fine-grained, per-entity filtering of
+        ///   collisions.
+        #[must_use]
+        pub fn intersections_with_aabb_with_filter<F>(
+            &self,
+            min: Vec3,
+            max: Vec3,
+            layers: CollisionLayers,
+            filter: F,
+        ) -> Vec<Entity>
+        where
+            F: Fn(Entity) -> bool,
+        {
+            self.intersections_with_aabb_internal(min, max, layers, Some(&filter))
+        }
+
+        #[must_use]
+        fn intersections_with_aabb_internal(
+            &self,
+            min: Vec3,
+            max: Vec3,
+            layers: CollisionLayers,
+            filter: Option<&dyn Fn(Entity) -> bool>,
+        ) -> Vec<Entity> {
+            // An axis-aligned box is nothing more than a non-rotated cuboid
+            self.intersections_with_shape_internal(
+                &CollisionShape::Cuboid {
+                    half_extends: (max - min).abs() / 2.0,
+                    border_radius: None,
+                },
+                (min + max) / 2.0,
+                Quat::IDENTITY,
+                layers,
+                filter,
+            )
+        }
+
+        /// Get every collision shape entity intersecting the given shape
+        ///
+        /// - `shape`: The [`CollisionShape`] to test
+        /// - `position`: The position of the shape, in world space
+        /// - `rotation`: The rotation of the shape, in world space
+        ///
+        /// Returns an empty list if the shape is invalid (see [`heron_core::CollisionShapeError`]).
+        #[must_use]
+        pub fn intersections_with_shape(
+            &self,
+            shape: &CollisionShape,
+            position: Vec3,
+            rotation: Quat,
+        ) -> Vec<Entity> {
+            self.intersections_with_shape_internal(
+                shape,
+                position,
+                rotation,
+                CollisionLayers::default(),
+                None,
+            )
+        }
+
+        /// Get every collision shape entity intersecting the given shape, with extra filters
+        ///
+        /// Behaves the same as [`intersections_with_shape`](Self::intersections_with_shape) but
+        /// takes extra arguments for filtering results:
+        ///
+        /// - `layers`: The [`CollisionLayers`] to considered for collisions, allowing for coarse
+        ///   filtering of collisions.
+        /// - `filter`: A closure taking an [`Entity`] and returning `true` if the entity should be
+        ///   considered for collisions, allowing for fine-grained, per-entity filtering of
+        ///   collisions.
+        #[must_use]
+        pub fn intersections_with_shape_with_filter<F>(
+            &self,
+            shape: &CollisionShape,
+            position: Vec3,
+            rotation: Quat,
+            layers: CollisionLayers,
+            filter: F,
+        ) -> Vec<Entity>
+        where
+            F: Fn(Entity) -> bool,
+        {
+            self.intersections_with_shape_internal(shape, position, rotation, layers, Some(&filter))
+        }
+
+        #[must_use]
+        fn intersections_with_shape_internal(
+            &self,
+            shape: &CollisionShape,
+            position: Vec3,
+            rotation: Quat,
+            layers: CollisionLayers,
+            filter: Option<&dyn Fn(Entity) -> bool>,
+        ) -> Vec<Entity> {
+            let collider = match shape.collider_builder() {
+                Ok(builder) => builder.build(),
+                Err(_) => return Vec::new(),
+            };
+            let mut entities = Vec::new();
+
+            self.query_pipeline.intersections_with_shape(
+                &*self.colliders,
+                &(position, rotation).into_rapier(),
+                collider.shape(),
+                layers.into_rapier(),
+                Some(&self.collider_filter(filter)),
+                |handle| {
+                    entities.extend(self.entity(handle));
+                    true
+                },
+            );
+
+            entities
+        }
+
+        /// Maps an entity filter to the collider handle filter expected by rapier
+        ///
+        /// Every collider passes the returned filter if no entity filter is given.
+        fn collider_filter<'a>(
+            &'a self,
+            filter: Option<&'a dyn Fn(Entity) -> bool>,
+        ) -> impl Fn(ColliderHandle) -> bool + 'a {
+            move |handle| filter.map_or(true, |filter| self.entity(handle).map_or(false, filter))
+        }
+
+        /// Collision shape entity of the given collider
+        #[allow(clippy::cast_possible_truncation)]
+        fn entity(&self, handle: ColliderHandle) -> Option<Entity> {
+            self.colliders
+                .get(handle)
+                .map(|collider| Entity::from_bits(collider.user_data as u64))
+        }
     }
 }
 
@@ -363,8 +664,15 @@ pub(crate) fn step(
     mut ccd_solver: ResMut<'_, CCDSolver>,
     event_manager: Local<'_, EventManager>,
     mut events: ResMut<'_, Events<CollisionEvent>>,
+    mut contact_events: ResMut<'_, Events<CollisionContactEvent>>,
+    hooks: Option<Res<'_, PhysicsHooksResource>>,
 ) {
     let gravity = Vec3::from(*gravity).into_rapier();
+    let hooks_adapter = hooks.as_deref().map(HooksAdapter::new);
+    let hooks: &dyn PhysicsHooks<RigidBodySet, ColliderSet> = match &hooks_adapter {
+        Some(adapter) => adapter,
+        None => &(),
+    };
 
     // Step the physics simulation
     pipeline.step(
@@ -377,7 +685,7 @@ pub(crate) fn step(
         &mut colliders,
         &mut joints,
         &mut ccd_solver,
-        &(),
+        hooks,
         &*event_manager,
     );
 
@@ -385,13 +693,65 @@ pub(crate) fn step(
     query_pipeline.update(&islands, &bodies, &colliders);
 
     event_manager.fire_events(&bodies, &colliders, &mut events);
+    event_manager.fire_contact_events(&bodies, &colliders, &narrow_phase, &mut contact_events);
 }
 
 pub(crate) struct EventManager {
     contact_recv: Receiver<ContactEvent>,
     intersection_recv: Receiver<IntersectionEvent>,
+    started_contact_recv: Receiver<(ColliderHandle, ColliderHandle)>,
     contact_send: Sender<ContactEvent>,
     intersection_send: Sender<IntersectionEvent>,
+    started_contact_send: Sender<(ColliderHandle, ColliderHandle)>,
+}
+
+/// Contact points of a pair of colliders that started to be in contact
+///
+/// It is read from the narrow phase once the step is complete, so that the impulses are the ones
+/// computed by the solver (contact events are fired before the solver runs).
+struct ContactManifolds {
+    collider1: ColliderHandle,
+    collider2: ColliderHandle,
+    points: Vec<RawContactPoint>,
+}
+
+/// Contact point as computed by rapier, with points and normals in the local space of each collider
+struct RawContactPoint {
+    local_point1: Point<f32>,
+    local_point2: Point<f32>,
+    local_normal1: Vector<f32>,
+    local_normal2: Vector<f32>,
+    dist: f32,
+    impulse: f32,
+}
+
+impl ContactManifolds {
+    fn from_pair(pair: &ContactPair) -> Self {
+        Self {
+            collider1: pair.collider1,
+            collider2: pair.collider2,
+            points: pair
+                .manifolds
+                .iter()
+                .flat_map(|manifold| {
+                    // The points and normals of a manifold are relative to the subshapes in
+                    // contact, which are offset from their collider in composite shapes
+                    let subshape1 = manifold.subshape_pos1.unwrap_or_else(Isometry::identity);
+                    let subshape2 = manifold.subshape_pos2.unwrap_or_else(Isometry::identity);
+                    let local_normal1 = subshape1 * manifold.local_n1;
+                    let local_normal2 = subshape2 * manifold.local_n2;
+                    manifold.points.iter().map(move |point| RawContactPoint {
+                        local_point1: subshape1 * point.local_p1,
+                        local_point2: subshape2 * point.local_p2,
+                        local_normal1,
+                        local_normal2,
+                        dist: point.dist,
+                        impulse: point.data.impulse,
+                    })
+                })
+                .collect(),
+        }
+    }
 }
 
 impl EventHandler for EventManager {
@@ -401,7 +761,13 @@ impl EventHandler for EventManager {
         }
     }
 
-    fn handle_contact_event(&self, event: ContactEvent, _: &crate::rapier::prelude::ContactPair) {
+    fn handle_contact_event(&self, event: ContactEvent, _: &ContactPair) {
+        if let ContactEvent::Started(h1, h2) = event {
+            if self.started_contact_send.send((h1, h2)).is_err() {
+                error!("Failed to forward started contact!");
+            }
+        }
+
         if self.contact_send.send(event).is_err() {
             error!("Failed to forward contact event!");
         }
@@ -412,11 +778,14 @@ impl Default for EventManager {
     fn default() -> Self {
         let (contact_send, contact_recv) = crossbeam::channel::unbounded();
         let (intersection_send, intersection_recv) = crossbeam::channel::unbounded();
+        let (started_contact_send, started_contact_recv) = crossbeam::channel::unbounded();
         Self {
             contact_recv,
             intersection_recv,
+            started_contact_recv,
             contact_send,
             intersection_send,
+            started_contact_send,
         }
     }
 }
@@ -459,46 +828,99 @@ impl EventManager {
         }
     }
 
+    fn fire_contact_events(
+        &self,
+        bodies: &RigidBodySet,
+        colliders: &ColliderSet,
+        narrow_phase: &NarrowPhase,
+        events: &mut Events<CollisionContactEvent>,
+    ) {
+        while let Ok((h1, h2)) = self.started_contact_recv.try_recv() {
+            if let Some(event) = narrow_phase.contact_pair(h1, h2).and_then(|pair| {
+                Self::contact_event(bodies, colliders, &ContactManifolds::from_pair(pair))
+            }) {
+                events.send(event);
+            }
+        }
+    }
+
     #[allow(clippy::cast_possible_truncation)]
+    fn contact_event(
+        bodies: &RigidBodySet,
+        colliders: &ColliderSet,
+        manifolds: &ContactManifolds,
+    ) -> Option<CollisionContactEvent> {
+        let (d1, d2) = Self::data(bodies, colliders, manifolds.collider1, manifolds.collider2)?;
+        let first = colliders.get(manifolds.collider1)?;
+        let second = colliders.get(manifolds.collider2)?;
+
+        // The data may have been swapped to be ordered by entity
+        let swapped = d1.collision_shape_entity() != Entity::from_bits(first.user_data as u64);
+
+        let points = manifolds
+            .points
+            .iter()
+            .map(|raw| {
+                let (point, normal) = if swapped {
+                    (
+                        second.position() * raw.local_point2,
+                        second.position() * raw.local_normal2,
+                    )
+                } else {
+                    (
+                        first.position() * raw.local_point1,
+                        first.position() * raw.local_normal1,
+                    )
+                };
+
+                let point = point.into_bevy();
+                #[cfg(dim2)]
+                let point = point.extend(0.);
+
+                ContactPoint {
+                    point,
+                    normal: normal.into_bevy(),
+                    penetration_depth: -raw.dist,
+                    impulse: raw.impulse,
+                }
+            })
+            .collect();
+
+        Some(CollisionContactEvent::new(d1, d2, points))
+    }
+
     fn data(
         bodies: &RigidBodySet,
         colliders: &ColliderSet,
         h1: ColliderHandle,
         h2: ColliderHandle,
     ) -> Option<(CollisionData, CollisionData)> {
-        if let (Some(collider1), Some(collider2)) = (colliders.get(h1), colliders.get(h2)) {
-            if let (Some(rb1), Some(rb2)) = (
-                collider1.parent().and_then(|parent| bodies.get(parent)),
-                collider2.parent().and_then(|parent| bodies.get(parent)),
-            ) {
-                let d1 = CollisionData::new(
-                    Entity::from_bits(rb1.user_data as u64),
-                    Entity::from_bits(collider1.user_data as u64),
-                    collider1.collision_groups().into_bevy(),
-                );
-                let d2 = CollisionData::new(
-                    Entity::from_bits(rb2.user_data as u64),
-                    Entity::from_bits(collider2.user_data as u64),
-                    collider2.collision_groups().into_bevy(),
-                );
-                Some(
-                    if Entity::from_bits(rb1.user_data as u64)
-                        < Entity::from_bits(rb2.user_data as u64)
-                    {
-                        (d1, d2)
-                    } else {
-                        (d2, d1)
-                    },
-                )
-            } else {
-                None
-            }
+        let d1 = collision_data(bodies, colliders, h1)?;
+        let d2 = collision_data(bodies, colliders, h2)?;
+        Some(if d1.rigid_body_entity() < d2.rigid_body_entity() {
+            (d1, d2)
         } else {
-            None
-        }
+            (d2, d1)
+        })
     }
 }
 
+/// Returns the collision data of the given collider, if it exists and is attached to a rigid body
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn collision_data(
+    bodies: &RigidBodySet,
+    colliders: &ColliderSet,
+    handle: ColliderHandle,
+) -> Option<CollisionData> {
+    let collider = colliders.get(handle)?;
+    let body = collider.parent().and_then(|parent| bodies.get(parent))?;
+    Some(CollisionData::new(
+        Entity::from_bits(body.user_data as u64),
+        Entity::from_bits(collider.user_data as u64),
+        collider.collision_groups().into_bevy(),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use bevy::prelude::*;
@@ -726,6 +1148,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn contact_manifolds_are_converted_to_collision_contact_event() {
+        let context = TestContext::default();
+
+        let event = EventManager::contact_event(
+            &context.bodies,
+            &context.colliders,
+            &ContactManifolds {
+                collider1: context.handle1,
+                collider2: context.handle2,
+                points: vec![RawContactPoint {
+                    local_point1: Vec3::X.into_rapier(),
+                    local_point2: (-Vec3::X).into_rapier(),
+                    local_normal1: Vec3::X.into_rapier(),
+                    local_normal2: (-Vec3::X).into_rapier(),
+                    dist: -0.5,
+                    impulse: 2.0,
+                }],
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            event.collision_shape_entities(),
+            (context.collider_entity_1, context.collider_entity_2)
+        );
+        assert_eq!(event.points().len(), 1);
+        assert_eq!(event.points()[0].point, Vec3::X);
+        assert_eq!(event.points()[0].normal, Vec3::X);
+        assert_eq!(event.points()[0].penetration_depth, 0.5);
+        assert_eq!(event.total_impulse(), 2.0);
+    }
+
+    #[test]
+    fn contact_points_follow_the_entity_order() {
+        let context = TestContext::default();
+
+        let event = EventManager::contact_event(
+            &context.bodies,
+            &context.colliders,
+            &ContactManifolds {
+                collider1: context.handle2,
+                collider2: context.handle1,
+                points: vec![RawContactPoint {
+                    local_point1: Vec3::X.into_rapier(),
+                    local_point2: (-Vec3::X).into_rapier(),
+                    local_normal1: Vec3::X.into_rapier(),
+                    local_normal2: (-Vec3::X).into_rapier(),
+                    dist: -0.5,
+                    impulse: 2.0,
+                }],
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            event.collision_shape_entities(),
+            (context.collider_entity_1, context.collider_entity_2)
+        );
+        assert_eq!(event.points()[0].point, -Vec3::X);
+        assert_eq!(event.points()[0].normal, -Vec3::X);
+    }
+
     /// Marker struct for Ray cast test collider shape
     #[derive(Component)]
     struct RayCastTestCollider;
@@ -903,4 +1388,164 @@ mod tests {
         app.update();
         app.update();
     }
+
+    #[test]
+    fn intersections_with_point() {
+        fn intersections(
+            mut runs: Local<'_, i32>,
+            physics_world: PhysicsWorld<'_, '_>,
+            test_colliders: Query<'_, '_, Entity, With<RayCastTestCollider>>,
+        ) {
+            // Skip the first run to give time for the world to setup
+            if *runs == 0 {
+                *runs = *runs + 1;
+                return;
+            }
+
+            let block = test_colliders.single();
+
+            assert_eq!(
+                physics_world.intersections_with_point(Vec3::new(0., 95., 0.)),
+                vec![block]
+            );
+            assert!(physics_world
+                .intersections_with_point(Vec3::default())
+                .is_empty());
+            assert!(physics_world
+                .intersections_with_point_with_filter(
+                    Vec3::new(0., 95., 0.),
+                    CollisionLayers::default(),
+                    |entity| entity != block
+                )
+                .is_empty());
+        }
+
+        let mut app = setup_ray_cast_test_app();
+        app.add_system(intersections.system());
+
+        app.update();
+        app.update();
+    }
+
+    #[test]
+    fn intersections_with_aabb() {
+        fn intersections(
+            mut runs: Local<'_, i32>,
+            physics_world: PhysicsWorld<'_, '_>,
+            test_colliders: Query<'_, '_, Entity, With<RayCastTestCollider>>,
+        ) {
+            // Skip the first run to give time for the world to setup
+            if *runs == 0 {
+                *runs = *runs + 1;
+                return;
+            }
+
+            assert_eq!(
+                physics_world
+                    .intersections_with_aabb(Vec3::new(-5., 80., -5.), Vec3::new(5., 95., 5.)),
+                vec![test_colliders.single()]
+            );
+            assert!(physics_world
+                .intersections_with_aabb(Vec3::new(-5., 0., -5.), Vec3::new(5., 85., 5.))
+                .is_empty());
+        }
+
+        let mut app = setup_ray_cast_test_app();
+        app.add_system(intersections.system());
+
+        app.update();
+        app.update();
+    }
+
+    #[test]
+    fn intersections_with_shape() {
+        fn intersections(
+            mut runs: Local<'_, i32>,
+            physics_world: PhysicsWorld<'_, '_>,
+            test_colliders: Query<'_, '_, Entity, With<RayCastTestCollider>>,
+        ) {
+            // Skip the first run to give time for the world to setup
+            if *runs == 0 {
+                *runs = *runs + 1;
+                return;
+            }
+
+            let shape = CollisionShape::Sphere { radius: 10. };
+
+            assert_eq!(
+                physics_world.intersections_with_shape(
+                    &shape,
+                    Vec3::new(0., 85., 0.),
+                    Quat::IDENTITY
+                ),
+                vec![test_colliders.single()]
+            );
+            assert!(physics_world
+                .intersections_with_shape(&shape, Vec3::new(0., 75., 0.), Quat::IDENTITY)
+                .is_empty());
+        }
+
+        let mut app = setup_ray_cast_test_app();
+        app.add_system(intersections.system());
+
+        app.update();
+        app.update();
+    }
+
+    #[test]
+    fn ray_cast_all_returns_every_hit_sorted_by_distance() {
+        fn setup(mut commands: Commands<'_, '_>) {
+            // Spawn a second block, behind the one spawned by setup_ray_cast_test_app()
+            commands.spawn_bundle((
+                CollisionShape::Cuboid {
+                    half_extends: Vec3::new(10., 10., 10.),
+                    border_radius: None,
+                },
+                RigidBody::Static,
+                Transform::from_xyz(0., 150., 0.),
+                GlobalTransform::default(),
+            ));
+        }
+
+        fn ray_cast(
+            mut runs: Local<'_, i32>,
+            physics_world: PhysicsWorld<'_, '_>,
+            test_colliders: Query<'_, '_, (), With<RayCastTestCollider>>,
+        ) {
+            // Skip the first run to give time for the world to setup
+            if *runs == 0 {
+                *runs = *runs + 1;
+                return;
+            }
+
+            let result = physics_world.ray_cast_all(Vec3::default(), Vec3::new(0., 200., 0.), true);
+
+            assert_eq!(result.len(), 2);
+            assert!(result[0].collision_point.distance(Vec3::new(0., 90., 0.)) < 0.1);
+            assert!(test_colliders.get(result[0].entity).is_ok());
+            assert!(result[1].collision_point.distance(Vec3::new(0., 140., 0.)) < 0.1);
+            assert!(test_colliders.get(result[1].entity).is_err());
+
+            let mut count = 0;
+            physics_world.ray_cast_with_callback(
+                Vec3::default(),
+                Vec3::new(0., 200., 0.),
+                true,
+                CollisionLayers::default(),
+                |_| true,
+                |_| {
+                    count += 1;
+                    false
+                },
+            );
+            assert_eq!(count, 1);
+        }
+
+        let mut app = setup_ray_cast_test_app();
+        app.add_startup_system(setup.system())
+            .add_system(ray_cast.system());
+
+        app.update();
+        app.update();
+    }
 }
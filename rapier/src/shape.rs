@@ -1,24 +1,56 @@
+use std::borrow::Cow;
+
 use bevy::prelude::*;
 use fnv::FnvHashMap;
 
-use heron_core::{CollisionLayers, CollisionShape, PhysicMaterial, RigidBody, SensorShape};
+use heron_core::{
+    ActiveCollisionEvents, ActiveCollisionHooks, CollisionLayers, CollisionShape,
+    CollisionShapeError, CollisionShapeErrorEvent, CompoundShapeChild, InvalidCollisionShape,
+    MassProperties, PhysicMaterial, RigidBody, SensorShape,
+};
 
 use crate::convert::IntoRapier;
 use crate::rapier::dynamics::{IslandManager, RigidBodySet};
 use crate::rapier::geometry::{
     ActiveCollisionTypes, Collider, ColliderBuilder, ColliderHandle, ColliderSet, InteractionGroups,
 };
-use crate::rapier::math::Point;
-use crate::rapier::pipeline::ActiveEvents;
+use crate::rapier::math::{Point, DIM};
+use crate::rapier::pipeline::{ActiveEvents, ActiveHooks};
+
+/// Resource mapping the entities of the collision shapes to their collider in the default physics
+/// world
+///
+/// Unlike the [`ColliderHandle`](crate::ColliderHandle) component, it never contains the
+/// colliders of the additional [`PhysicsWorlds`](crate::PhysicsWorlds), so that its handles can
+/// always be resolved against the `ColliderSet` resource.
+/// It is only useful for advanced, direct access to the rapier world
+pub type HandleMap = FnvHashMap<Entity, ColliderHandle>;
 
-pub(crate) type HandleMap = FnvHashMap<Entity, ColliderHandle>;
+/// Scale of the `GlobalTransform` with which the collider has been built
+#[derive(Debug, Component, Copy, Clone)]
+pub(crate) struct ShapeScale(Vec3);
+
+/// Tolerance under which a scale change doesn't cause the collider to be rebuilt
+const SCALE_TOLERANCE: f32 = 1e-4;
 
 pub(crate) fn create(
     mut commands: Commands<'_, '_>,
     mut bodies: ResMut<'_, RigidBodySet>,
     mut colliders: ResMut<'_, ColliderSet>,
     mut handles: ResMut<'_, HandleMap>,
-    rigid_bodies: Query<'_, '_, (&RigidBody, &super::RigidBodyHandle, Option<&PhysicMaterial>)>,
+    body_handles: Res<'_, crate::body::HandleMap>,
+    mut errors: EventWriter<'_, '_, CollisionShapeErrorEvent>,
+    rigid_bodies: Query<
+        '_,
+        '_,
+        (
+            &RigidBody,
+            &GlobalTransform,
+            Option<&PhysicMaterial>,
+            Option<&MassProperties>,
+        ),
+        With<super::RigidBodyHandle>,
+    >,
     collision_shapes: Query<
         '_,
         '_,
@@ -27,33 +59,66 @@ pub(crate) fn create(
             &CollisionShape,
             Option<&Parent>,
             Option<&Transform>,
+            Option<&GlobalTransform>,
+            Option<&PhysicMaterial>,
             Option<&CollisionLayers>,
             Option<&SensorShape>,
+            Option<&ActiveCollisionHooks>,
+            Option<&ActiveCollisionEvents>,
+        ),
+        (
+            Without<super::ColliderHandle>,
+            Without<InvalidCollisionShape>,
         ),
-        Without<super::ColliderHandle>,
     >,
 ) {
-    for (entity, shape, parent, transform, layers, sensor_flag) in collision_shapes.iter() {
-        let collider = if let Ok((body, rigid_body_handle, material)) = rigid_bodies.get(entity) {
+    // Only the bodies of the world being updated are in the handle map
+    let rigid_body = |entity: Entity| {
+        let handle = body_handles.get(&entity)?;
+        let (body, global, material, mass) = rigid_bodies.get(entity).ok()?;
+        Some((body, handle, global, material, mass))
+    };
+
+    for (
+        entity,
+        shape,
+        parent,
+        transform,
+        global,
+        shape_material,
+        layers,
+        sensor_flag,
+        hooks,
+        events,
+    ) in collision_shapes.iter()
+    {
+        let scale = global.map_or(Vec3::ONE, |global| global.scale);
+
+        let collider = if let Some((body, rigid_body_handle, _, material, mass)) =
+            rigid_body(entity)
+        {
             Some((
-                shape.build(
+                scaled(shape, scale).build(
                     entity,
                     sensor_flag.is_some() || matches!(body, RigidBody::Sensor),
-                    material,
+                    shape_material.or(material),
+                    mass,
                     None,
                     layers,
                 ),
                 rigid_body_handle,
             ))
-        } else if let Some((body, rigid_body_handle, material)) =
-            parent.and_then(|p| rigid_bodies.get(p.0).ok())
+        } else if let Some((body, rigid_body_handle, parent_global, material, mass)) =
+            parent.and_then(|p| rigid_body(p.0))
         {
+            let transform = transform.map(|transform| relative_to_parent(transform, parent_global));
             Some((
-                shape.build(
+                scaled(shape, scale).build(
                     entity,
                     sensor_flag.is_some() || matches!(body, RigidBody::Sensor),
-                    material,
-                    transform,
+                    shape_material.or(material),
+                    mass,
+                    transform.as_ref(),
                     layers,
                 ),
                 rigid_body_handle,
@@ -62,27 +127,79 @@ pub(crate) fn create(
             None
         };
 
-        if let Some((collider, rigid_body_handle)) = collider {
-            let handle = colliders.insert_with_parent(collider, rigid_body_handle.0, &mut bodies);
+        if let Some((result, rigid_body_handle)) = collider {
+            let mut collider = match result {
+                Ok(collider) => collider,
+                Err(error) => {
+                    warn!(
+                        "Failed to build the collision shape of {:?}: {}",
+                        entity, error
+                    );
+                    commands.entity(entity).insert(InvalidCollisionShape {
+                        error: error.clone(),
+                    });
+                    errors.send(CollisionShapeErrorEvent { entity, error });
+                    continue;
+                }
+            };
+
+            if let Some(hooks) = hooks {
+                collider.set_active_hooks(hooks.into_rapier());
+            }
+            if let Some(events) = events {
+                collider.set_active_events(events.into_rapier());
+            }
+            let handle = colliders.insert_with_parent(collider, *rigid_body_handle, &mut bodies);
             commands
                 .entity(entity)
-                .insert(super::ColliderHandle(handle));
+                .insert(super::ColliderHandle(handle))
+                .insert(ShapeScale(scale));
             handles.insert(entity, handle);
         }
     }
 }
 
+/// Returns the shape scaled by the given factors, without cloning it if the scale is one
+fn scaled(shape: &CollisionShape, scale: Vec3) -> Cow<'_, CollisionShape> {
+    if scale.abs_diff_eq(Vec3::ONE, SCALE_TOLERANCE) {
+        Cow::Borrowed(shape)
+    } else {
+        Cow::Owned(shape.scaled(scale))
+    }
+}
+
+/// The translation of a child shape is expressed in the scaled space of its parent
+fn relative_to_parent(transform: &Transform, parent: &GlobalTransform) -> Transform {
+    Transform {
+        translation: transform.translation * parent.scale,
+        ..*transform
+    }
+}
+
 pub(crate) fn update_position(
     mut colliders: ResMut<'_, ColliderSet>,
+    handles: Res<'_, HandleMap>,
+    parents: Query<'_, '_, &GlobalTransform, With<RigidBody>>,
     query: Query<
         '_,
         '_,
-        (&Transform, &super::ColliderHandle),
-        (Changed<Transform>, Without<RigidBody>),
+        (Entity, &Transform, Option<&Parent>),
+        (
+            Changed<Transform>,
+            With<super::ColliderHandle>,
+            Without<RigidBody>,
+        ),
     >,
 ) {
-    for (transform, handle) in query.iter() {
-        if let Some(collider) = colliders.get_mut(handle.0) {
+    for (entity, transform, parent) in query.iter() {
+        if let Some(collider) = handles
+            .get(&entity)
+            .and_then(|handle| colliders.get_mut(*handle))
+        {
+            let transform = match parent.and_then(|parent| parents.get(parent.0).ok()) {
+                Some(parent) => relative_to_parent(transform, parent),
+                None => *transform,
+            };
             collider
                 .set_position_wrt_parent((transform.translation, transform.rotation).into_rapier());
         }
@@ -91,10 +208,19 @@ pub(crate) fn update_position(
 
 pub(crate) fn update_collision_groups(
     mut colliders: ResMut<'_, ColliderSet>,
-    query: Query<'_, '_, (&CollisionLayers, &super::ColliderHandle), Changed<CollisionLayers>>,
+    handles: Res<'_, HandleMap>,
+    query: Query<
+        '_,
+        '_,
+        (Entity, &CollisionLayers),
+        (Changed<CollisionLayers>, With<super::ColliderHandle>),
+    >,
 ) {
-    for (layers, handle) in query.iter() {
-        if let Some(collider) = colliders.get_mut(handle.0) {
+    for (entity, layers) in query.iter() {
+        if let Some(collider) = handles
+            .get(&entity)
+            .and_then(|handle| colliders.get_mut(*handle))
+        {
             collider.set_collision_groups(layers.into_rapier());
         }
     }
@@ -102,10 +228,14 @@ pub(crate) fn update_collision_groups(
 
 pub(crate) fn update_sensor_flag(
     mut colliders: ResMut<'_, ColliderSet>,
-    query: Query<'_, '_, &super::ColliderHandle, Changed<SensorShape>>,
+    handles: Res<'_, HandleMap>,
+    query: Query<'_, '_, Entity, (Changed<SensorShape>, With<super::ColliderHandle>)>,
 ) {
-    for handle in query.iter() {
-        if let Some(collider) = colliders.get_mut(handle.0) {
+    for entity in query.iter() {
+        if let Some(collider) = handles
+            .get(&entity)
+            .and_then(|handle| colliders.get_mut(*handle))
+        {
             collider.set_sensor(true);
         }
     }
@@ -116,14 +246,14 @@ pub(crate) fn remove_sensor_flag(
     bodies: Res<'_, RigidBodySet>,
     mut colliders: ResMut<'_, ColliderSet>,
     rigid_bodies: Query<'_, '_, &RigidBody>,
-    collider_handles: Query<'_, '_, &super::ColliderHandle>,
+    collider_handles: Res<'_, HandleMap>,
     removed: RemovedComponents<'_, SensorShape>,
 ) {
     removed
         .iter()
-        .filter_map(|e| collider_handles.get(e).ok())
+        .filter_map(|e| collider_handles.get(&e))
         .for_each(|handle| {
-            if let Some(collider) = colliders.get_mut(handle.0) {
+            if let Some(collider) = colliders.get_mut(*handle) {
                 let rigid_body = collider.parent().and_then(|parent| {
                     bodies
                         .get(parent)
@@ -138,19 +268,89 @@ pub(crate) fn remove_sensor_flag(
 
 pub(crate) fn reset_collision_groups(
     mut colliders: ResMut<'_, ColliderSet>,
-    handles: Query<'_, '_, &super::ColliderHandle>,
+    handles: Res<'_, HandleMap>,
     removed: RemovedComponents<'_, CollisionLayers>,
 ) {
     removed
         .iter()
-        .filter_map(|entity| handles.get(entity).ok())
+        .filter_map(|entity| handles.get(&entity))
         .for_each(|handle| {
-            if let Some(collider) = colliders.get_mut(handle.0) {
+            if let Some(collider) = colliders.get_mut(*handle) {
                 collider.set_collision_groups(InteractionGroups::default());
             }
         });
 }
 
+pub(crate) fn update_active_hooks(
+    mut colliders: ResMut<'_, ColliderSet>,
+    handles: Res<'_, HandleMap>,
+    query: Query<
+        '_,
+        '_,
+        (Entity, &ActiveCollisionHooks),
+        (Changed<ActiveCollisionHooks>, With<super::ColliderHandle>),
+    >,
+) {
+    for (entity, hooks) in query.iter() {
+        if let Some(collider) = handles
+            .get(&entity)
+            .and_then(|handle| colliders.get_mut(*handle))
+        {
+            collider.set_active_hooks(hooks.into_rapier());
+        }
+    }
+}
+
+pub(crate) fn reset_active_hooks(
+    mut colliders: ResMut<'_, ColliderSet>,
+    handles: Res<'_, HandleMap>,
+    removed: RemovedComponents<'_, ActiveCollisionHooks>,
+) {
+    removed
+        .iter()
+        .filter_map(|entity| handles.get(&entity))
+        .for_each(|handle| {
+            if let Some(collider) = colliders.get_mut(*handle) {
+                collider.set_active_hooks(ActiveHooks::empty());
+            }
+        });
+}
+
+pub(crate) fn update_active_events(
+    mut colliders: ResMut<'_, ColliderSet>,
+    handles: Res<'_, HandleMap>,
+    query: Query<
+        '_,
+        '_,
+        (Entity, &ActiveCollisionEvents),
+        (Changed<ActiveCollisionEvents>, With<super::ColliderHandle>),
+    >,
+) {
+    for (entity, events) in query.iter() {
+        if let Some(collider) = handles
+            .get(&entity)
+            .and_then(|handle| colliders.get_mut(*handle))
+        {
+            collider.set_active_events(events.into_rapier());
+        }
+    }
+}
+
+pub(crate) fn reset_active_events(
+    mut colliders: ResMut<'_, ColliderSet>,
+    handles: Res<'_, HandleMap>,
+    removed: RemovedComponents<'_, ActiveCollisionEvents>,
+) {
+    removed
+        .iter()
+        .filter_map(|entity| handles.get(&entity))
+        .for_each(|handle| {
+            if let Some(collider) = colliders.get_mut(*handle) {
+                collider.set_active_events(ActiveEvents::empty());
+            }
+        });
+}
+
 pub(crate) fn remove_invalids_after_components_removed(
     mut commands: Commands<'_, '_>,
     mut handles: ResMut<'_, HandleMap>,
@@ -177,36 +377,163 @@ pub(crate) fn remove_invalids_after_component_changed(
     mut bodies: ResMut<'_, RigidBodySet>,
     mut islands: ResMut<'_, IslandManager>,
     mut colliders: ResMut<'_, ColliderSet>,
-    changed: Query<'_, '_, (Entity, &super::ColliderHandle), Changed<CollisionShape>>,
+    changed: Query<'_, '_, Entity, (Changed<CollisionShape>, With<super::ColliderHandle>)>,
+) {
+    for entity in changed.iter() {
+        if let Some(handle) = handles.remove(&entity) {
+            colliders.remove(handle, &mut islands, &mut bodies, true);
+            commands.entity(entity).remove::<super::ColliderHandle>();
+        }
+    }
+}
+
+/// Updates the colliders whose material (or the material of their rigid body) has changed
+///
+/// The friction and restitution are updated in place. The colliders whose density has changed are
+/// removed, so that they are built again, and the mass of their rigid body is updated.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn update_materials(
+    mut commands: Commands<'_, '_>,
+    mut handles: ResMut<'_, HandleMap>,
+    mut bodies: ResMut<'_, RigidBodySet>,
+    mut islands: ResMut<'_, IslandManager>,
+    mut colliders: ResMut<'_, ColliderSet>,
+    body_handles: Res<'_, crate::body::HandleMap>,
+    materials: Query<'_, '_, &PhysicMaterial>,
+    parents: Query<'_, '_, &Parent>,
+    changed: Query<'_, '_, Entity, Changed<PhysicMaterial>>,
+    removed: RemovedComponents<'_, PhysicMaterial>,
 ) {
-    for (entity, handle) in changed.iter() {
-        colliders.remove(handle.0, &mut islands, &mut bodies, true);
-        commands.entity(entity).remove::<super::ColliderHandle>();
-        handles.remove(&entity);
+    let mut affected: Vec<Entity> = Vec::new();
+    for entity in changed.iter().chain(removed.iter()) {
+        if handles.contains_key(&entity) {
+            affected.push(entity);
+        }
+
+        // The other colliders of the rigid body, unless they define their own material
+        if let Some(body) = body_handles
+            .get(&entity)
+            .and_then(|handle| bodies.get(*handle))
+        {
+            affected.extend(
+                body.colliders()
+                    .iter()
+                    .filter_map(|handle| colliders.get(*handle))
+                    .map(collider_entity)
+                    .filter(|child| *child != entity && materials.get(*child).is_err()),
+            );
+        }
+    }
+
+    for entity in affected {
+        let material = materials
+            .get(entity)
+            .or_else(|_| {
+                parents
+                    .get(entity)
+                    .and_then(|parent| materials.get(parent.0))
+            })
+            .copied()
+            .unwrap_or_default();
+
+        let handle = match handles.get(&entity) {
+            Some(handle) => *handle,
+            None => continue,
+        };
+
+        if let Some(collider) = colliders.get_mut(handle) {
+            if collider.density().map_or(false, |density| {
+                (density - material.density).abs() > f32::EPSILON
+            }) {
+                colliders.remove(handle, &mut islands, &mut bodies, true);
+                commands.entity(entity).remove::<super::ColliderHandle>();
+                handles.remove(&entity);
+            } else {
+                collider.set_restitution(material.restitution);
+                collider
+                    .set_restitution_combine_rule(material.restitution_combine_rule.into_rapier());
+                collider.set_friction(material.friction);
+                collider.set_friction_combine_rule(material.friction_combine_rule.into_rapier());
+            }
+        }
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn collider_entity(collider: &Collider) -> Entity {
+    Entity::from_bits(collider.user_data as u64)
+}
+
+/// Removes the colliders whose scale has changed, so that they are built again with the new scale
+pub(crate) fn remove_invalids_after_scale_changed(
+    mut commands: Commands<'_, '_>,
+    mut handles: ResMut<'_, HandleMap>,
+    mut bodies: ResMut<'_, RigidBodySet>,
+    mut islands: ResMut<'_, IslandManager>,
+    mut colliders: ResMut<'_, ColliderSet>,
+    changed: Query<
+        '_,
+        '_,
+        (Entity, &GlobalTransform, &ShapeScale),
+        (Changed<GlobalTransform>, With<super::ColliderHandle>),
+    >,
+) {
+    for (entity, transform, ShapeScale(scale)) in changed.iter() {
+        if transform.scale.abs_diff_eq(*scale, SCALE_TOLERANCE) {
+            continue;
+        }
+
+        if let Some(handle) = handles.remove(&entity) {
+            colliders.remove(handle, &mut islands, &mut bodies, true);
+            commands.entity(entity).remove::<super::ColliderHandle>();
+        }
+    }
+}
+
+pub(crate) fn remove_invalid_flags(
+    mut commands: Commands<'_, '_>,
+    changed: Query<'_, '_, Entity, (Changed<CollisionShape>, With<InvalidCollisionShape>)>,
+    flagged: Query<'_, '_, Entity, With<InvalidCollisionShape>>,
+    shapes_removed: RemovedComponents<'_, CollisionShape>,
+) {
+    let removed = shapes_removed
+        .iter()
+        .filter(|entity| flagged.get(*entity).is_ok());
+
+    for entity in changed.iter().chain(removed) {
+        commands.entity(entity).remove::<InvalidCollisionShape>();
     }
 }
 
 pub(crate) trait ColliderFactory {
-    fn collider_builder(&self) -> ColliderBuilder;
+    fn collider_builder(&self) -> Result<ColliderBuilder, CollisionShapeError>;
 
     fn build(
         &self,
         entity: Entity,
         is_sensor: bool,
         material: Option<&PhysicMaterial>,
+        mass_properties: Option<&MassProperties>,
         transform: Option<&Transform>,
         layers: Option<&CollisionLayers>,
-    ) -> Collider {
+    ) -> Result<Collider, CollisionShapeError> {
         let mut builder = self
-            .collider_builder()
+            .collider_builder()?
             .user_data(entity.to_bits().into())
             .sensor(is_sensor);
 
         if let Some(material) = material {
             builder = builder
                 .restitution(material.restitution)
+                .restitution_combine_rule(material.restitution_combine_rule.into_rapier())
                 .density(material.density)
-                .friction(material.friction);
+                .friction(material.friction)
+                .friction_combine_rule(material.friction_combine_rule.into_rapier());
+        }
+
+        // When the mass of the body is defined explicitly, the colliders must not add any mass to it
+        if mass_properties.is_some() {
+            builder = builder.mass_properties(crate::rapier::na::zero());
         }
 
         if let Some(transform) = transform {
@@ -217,74 +544,113 @@ pub(crate) trait ColliderFactory {
             builder = builder.collision_groups(layers.into_rapier());
         }
 
-        builder
+        Ok(builder
             .active_collision_types(ActiveCollisionTypes::all()) // Activate all collision types
-            .build()
+            .build())
     }
 }
 
 impl ColliderFactory for CollisionShape {
-    fn collider_builder(&self) -> ColliderBuilder {
-        match self {
-            CollisionShape::Sphere { radius } => ColliderBuilder::ball(*radius),
+    fn collider_builder(&self) -> Result<ColliderBuilder, CollisionShapeError> {
+        Ok(match self {
+            CollisionShape::Sphere { radius } => {
+                check_dimensions(&[*radius])?;
+                ColliderBuilder::ball(*radius)
+            }
             CollisionShape::Capsule {
                 half_segment: half_height,
                 radius,
-            } => ColliderBuilder::capsule_y(*half_height, *radius),
+            } => {
+                check_dimensions(&[*half_height, *radius])?;
+                ColliderBuilder::capsule_y(*half_height, *radius)
+            }
             CollisionShape::Cuboid {
                 half_extends,
                 border_radius,
-            } => cuboid_builder(*half_extends, *border_radius),
+            } => cuboid_builder(*half_extends, *border_radius)?,
             CollisionShape::ConvexHull {
                 points,
                 border_radius,
-            } => convex_hull_builder(points.as_slice(), *border_radius),
-            CollisionShape::HeightField { size, heights } => heightfield_builder(*size, heights),
+            } => convex_hull_builder(points.as_slice(), *border_radius)?,
+            CollisionShape::HeightField { size, heights } => heightfield_builder(*size, heights)?,
             #[cfg(dim3)]
             CollisionShape::Cone {
                 half_height,
                 radius,
-            } => ColliderBuilder::cone(*half_height, *radius),
+            } => {
+                check_dimensions(&[*half_height, *radius])?;
+                ColliderBuilder::cone(*half_height, *radius)
+            }
             #[cfg(dim3)]
             CollisionShape::Cylinder {
                 half_height,
                 radius,
-            } => ColliderBuilder::cylinder(*half_height, *radius),
-            CollisionShape::Custom { shape } => {
-                if let Some(builder) = shape.downcast_ref::<ColliderBuilder>() {
-                    builder.clone()
-                } else {
-                    panic!("Unsupported custom collision shape is used: {:?}", shape);
-                }
+            } => {
+                check_dimensions(&[*half_height, *radius])?;
+                ColliderBuilder::cylinder(*half_height, *radius)
             }
-            any_other => {
-                warn!(
-                    "Tried to build an nonexistent CollisionShape {:?}, falling back to a Sphere",
-                    any_other
-                );
-                ColliderBuilder::ball(1.0)
+            CollisionShape::Segment { a, b } => {
+                let [a, b]: [Point<f32>; 2] = [a.into_rapier(), b.into_rapier()];
+                check_vertices(&[a, b])?;
+                ColliderBuilder::segment(a, b)
             }
-        }
-        // General all types of collision events
-        .active_events(ActiveEvents::all())
+            CollisionShape::Triangle { a, b, c } => {
+                let [a, b, c]: [Point<f32>; 3] =
+                    [a.into_rapier(), b.into_rapier(), c.into_rapier()];
+                check_vertices(&[a, b, c])?;
+                ColliderBuilder::triangle(a, b, c)
+            }
+            CollisionShape::Polyline { vertices, indices } => {
+                polyline_builder(vertices, indices.as_deref())?
+            }
+            CollisionShape::TriMesh { vertices, indices } => trimesh_builder(vertices, indices)?,
+            CollisionShape::Compound { shapes } => compound_builder(shapes)?,
+            CollisionShape::Custom { shape } => shape
+                .downcast_ref::<ColliderBuilder>()
+                .cloned()
+                .ok_or_else(|| CollisionShapeError::UnsupportedCustomShape(shape.type_name()))?,
+            _ => return Err(CollisionShapeError::UnsupportedShape),
+        })
+    }
+}
+
+/// Returns an error if any of the dimensions is negative or not finite
+fn check_dimensions(dimensions: &[f32]) -> Result<(), CollisionShapeError> {
+    if dimensions
+        .iter()
+        .all(|dimension| dimension.is_finite() && *dimension >= 0.0)
+    {
+        Ok(())
+    } else {
+        Err(CollisionShapeError::InvalidDimension)
     }
 }
 
 #[inline]
 #[cfg(dim2)]
-fn cuboid_builder(half_extends: Vec3, border_radius: Option<f32>) -> ColliderBuilder {
-    border_radius.map_or_else(
+fn cuboid_builder(
+    half_extends: Vec3,
+    border_radius: Option<f32>,
+) -> Result<ColliderBuilder, CollisionShapeError> {
+    check_dimensions(&[half_extends.x, half_extends.y])?;
+    check_dimensions(&[border_radius.unwrap_or_default()])?;
+    Ok(border_radius.map_or_else(
         || ColliderBuilder::cuboid(half_extends.x, half_extends.y),
         |border_radius| {
             ColliderBuilder::round_cuboid(half_extends.x, half_extends.y, border_radius)
         },
-    )
+    ))
 }
 
 #[inline]
 #[cfg(dim3)]
-fn cuboid_builder(half_extends: Vec3, border_radius: Option<f32>) -> ColliderBuilder {
-    border_radius.map_or_else(
+fn cuboid_builder(
+    half_extends: Vec3,
+    border_radius: Option<f32>,
+) -> Result<ColliderBuilder, CollisionShapeError> {
+    check_dimensions(&half_extends.to_array())?;
+    check_dimensions(&[border_radius.unwrap_or_default()])?;
+    Ok(border_radius.map_or_else(
         || ColliderBuilder::cuboid(half_extends.x, half_extends.y, half_extends.z),
         |border_radius| {
             ColliderBuilder::round_cuboid(
@@ -294,42 +660,155 @@ fn cuboid_builder(half_extends: Vec3, border_radius: Option<f32>) -> ColliderBui
                 border_radius,
             )
         },
-    )
+    ))
 }
 
 #[inline]
-fn convex_hull_builder(points: &[Vec3], border_radius: Option<f32>) -> ColliderBuilder {
+fn convex_hull_builder(
+    points: &[Vec3],
+    border_radius: Option<f32>,
+) -> Result<ColliderBuilder, CollisionShapeError> {
+    check_dimensions(&[border_radius.unwrap_or_default()])?;
     let points: Vec<Point<f32>> = points.into_rapier();
-    border_radius.map_or_else(
-        || ColliderBuilder::convex_hull(points.as_slice()).expect("Failed to create convex-hull"),
-        |border_radius| {
-            ColliderBuilder::round_convex_hull(points.as_slice(), border_radius)
-                .expect("Failed to create convex-hull")
-        },
-    )
+    if points.len() <= DIM || !points.iter().flat_map(Point::iter).all(|x| x.is_finite()) {
+        return Err(CollisionShapeError::InvalidConvexHull);
+    }
+    border_radius
+        .map_or_else(
+            || ColliderBuilder::convex_hull(points.as_slice()),
+            |border_radius| ColliderBuilder::round_convex_hull(points.as_slice(), border_radius),
+        )
+        .ok_or(CollisionShapeError::InvalidConvexHull)
+}
+
+#[inline]
+fn polyline_builder(
+    vertices: &[Vec3],
+    indices: Option<&[[u32; 2]]>,
+) -> Result<ColliderBuilder, CollisionShapeError> {
+    let vertices: Vec<Point<f32>> = vertices.into_rapier();
+    check_vertices(&vertices)?;
+    let indices: Vec<[u32; 2]> = match indices {
+        Some(indices) => {
+            check_indices(indices, vertices.len())?;
+            indices.to_vec()
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        None => (1..vertices.len() as u32).map(|i| [i - 1, i]).collect(),
+    };
+
+    if indices.is_empty() {
+        return Err(CollisionShapeError::EmptyShape);
+    }
+
+    Ok(ColliderBuilder::polyline(vertices, Some(indices)))
+}
+
+#[inline]
+fn trimesh_builder(
+    vertices: &[Vec3],
+    indices: &[[u32; 3]],
+) -> Result<ColliderBuilder, CollisionShapeError> {
+    let vertices: Vec<Point<f32>> = vertices.into_rapier();
+    check_vertices(&vertices)?;
+    check_indices(indices, vertices.len())?;
+
+    if indices.is_empty() {
+        return Err(CollisionShapeError::EmptyShape);
+    }
+
+    Ok(ColliderBuilder::trimesh(vertices, indices.to_vec()))
+}
+
+#[inline]
+fn compound_builder(shapes: &[CompoundShapeChild]) -> Result<ColliderBuilder, CollisionShapeError> {
+    if shapes.iter().any(|child| child.shape.is_composite()) {
+        return Err(CollisionShapeError::NestedCompound);
+    }
+
+    let shapes = shapes
+        .iter()
+        .map(|child| {
+            Ok((
+                (child.translation, child.rotation).into_rapier(),
+                child.shape.collider_builder()?.shape,
+            ))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if shapes.is_empty() {
+        return Err(CollisionShapeError::EmptyShape);
+    }
+
+    Ok(ColliderBuilder::compound(shapes))
+}
+
+/// Returns an error if any coordinate of the vertices is infinite or NaN
+fn check_vertices(vertices: &[Point<f32>]) -> Result<(), CollisionShapeError> {
+    if vertices.iter().flat_map(Point::iter).all(|x| x.is_finite()) {
+        Ok(())
+    } else {
+        Err(CollisionShapeError::NonFiniteVertex)
+    }
+}
+
+/// Returns an error if any of the indices refers to a vertex that doesn't exist
+fn check_indices<const N: usize>(
+    indices: &[[u32; N]],
+    vertex_count: usize,
+) -> Result<(), CollisionShapeError> {
+    if indices
+        .iter()
+        .flatten()
+        .all(|i| (*i as usize) < vertex_count)
+    {
+        Ok(())
+    } else {
+        Err(CollisionShapeError::IndexOutOfBounds)
+    }
 }
 
 #[inline]
 #[cfg(dim2)]
 #[allow(clippy::cast_precision_loss)]
-fn heightfield_builder(size: Vec2, heights: &[Vec<f32>]) -> ColliderBuilder {
-    let len = heights.get(0).map(Vec::len).unwrap_or_default();
-    ColliderBuilder::heightfield(
-        crate::rapier::na::DVector::from_iterator(len, heights.iter().flatten().take(len).copied()),
+fn heightfield_builder(
+    size: Vec2,
+    heights: &[Vec<f32>],
+) -> Result<ColliderBuilder, CollisionShapeError> {
+    let heights = heights.get(0).map(Vec::as_slice).unwrap_or_default();
+    if heights.len() < 2 || !heights.iter().all(|height| height.is_finite()) {
+        return Err(CollisionShapeError::InvalidHeightField);
+    }
+    check_dimensions(&[size.x])?;
+
+    Ok(ColliderBuilder::heightfield(
+        crate::rapier::na::DVector::from_column_slice(heights),
         crate::rapier::na::Vector2::new(size.x, 1.0),
-    )
+    ))
 }
 
 #[inline]
 #[cfg(dim3)]
 #[allow(clippy::cast_precision_loss)]
-fn heightfield_builder(size: Vec2, heights: &[Vec<f32>]) -> ColliderBuilder {
+fn heightfield_builder(
+    size: Vec2,
+    heights: &[Vec<f32>],
+) -> Result<ColliderBuilder, CollisionShapeError> {
     let nrows = heights.len();
     let ncols = heights.get(0).map(Vec::len).unwrap_or_default();
-    ColliderBuilder::heightfield(
+    if nrows < 2
+        || ncols < 2
+        || heights.iter().any(|row| row.len() != ncols)
+        || !heights.iter().flatten().all(|height| height.is_finite())
+    {
+        return Err(CollisionShapeError::InvalidHeightField);
+    }
+    check_dimensions(&[size.x, size.y])?;
+
+    Ok(ColliderBuilder::heightfield(
         crate::rapier::na::DMatrix::from_iterator(nrows, ncols, heights.iter().flatten().copied()),
         crate::rapier::na::Vector3::new(size.x, 1.0, size.y),
-    )
+    ))
 }
 
 #[cfg(test)]
@@ -344,6 +823,7 @@ mod tests {
     fn build_sphere() {
         let collider = CollisionShape::Sphere { radius: 4.2 }
             .collider_builder()
+            .unwrap()
             .build();
 
         let ball = collider
@@ -360,6 +840,7 @@ mod tests {
             border_radius: None,
         }
         .collider_builder()
+        .unwrap()
         .build();
 
         let cuboid = collider
@@ -381,6 +862,7 @@ mod tests {
             radius: 5.0,
         }
         .collider_builder()
+        .unwrap()
         .build();
 
         let capsule = collider
@@ -408,6 +890,7 @@ mod tests {
             heights: vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]],
         }
         .collider_builder()
+        .unwrap()
         .build();
 
         let field = collider
@@ -434,12 +917,158 @@ mod tests {
         }
     }
 
+    #[test]
+    fn build_segment() {
+        let collider = CollisionShape::Segment {
+            a: Vec3::new(-1.0, 0.0, 0.0),
+            b: Vec3::new(1.0, 2.0, 0.0),
+        }
+        .collider_builder()
+        .unwrap()
+        .build();
+
+        let segment = collider
+            .shape()
+            .as_segment()
+            .expect("Created shape was not a segment");
+
+        assert_eq!(segment.a.x, -1.0);
+        assert_eq!(segment.b.y, 2.0);
+    }
+
+    #[test]
+    fn build_triangle() {
+        let collider = CollisionShape::Triangle {
+            a: Vec3::ZERO,
+            b: Vec3::X,
+            c: Vec3::Y,
+        }
+        .collider_builder()
+        .unwrap()
+        .build();
+
+        let triangle = collider
+            .shape()
+            .as_triangle()
+            .expect("Created shape was not a triangle");
+
+        assert_eq!(triangle.b.x, 1.0);
+        assert_eq!(triangle.c.y, 1.0);
+    }
+
+    #[test]
+    fn build_polyline() {
+        let collider = CollisionShape::Polyline {
+            vertices: vec![Vec3::ZERO, Vec3::X, Vec3::new(1.0, 1.0, 0.0)],
+            indices: None,
+        }
+        .collider_builder()
+        .unwrap()
+        .build();
+
+        let polyline = collider
+            .shape()
+            .as_polyline()
+            .expect("Created shape was not a polyline");
+
+        assert_eq!(polyline.num_segments(), 2);
+    }
+
+    #[test]
+    fn build_trimesh() {
+        let collider = CollisionShape::TriMesh {
+            vertices: vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::new(1.0, 1.0, 0.0)],
+            indices: vec![[0, 1, 2], [1, 3, 2]],
+        }
+        .collider_builder()
+        .unwrap()
+        .build();
+
+        let trimesh = collider
+            .shape()
+            .as_trimesh()
+            .expect("Created shape was not a triangle mesh");
+
+        assert_eq!(trimesh.num_triangles(), 2);
+    }
+
+    #[test]
+    fn build_compound() {
+        let collider = CollisionShape::Compound {
+            shapes: vec![
+                CompoundShapeChild::new(CollisionShape::Sphere { radius: 1.0 })
+                    .with_translation(Vec3::X * -2.0),
+                CompoundShapeChild::new(CollisionShape::Sphere { radius: 1.0 })
+                    .with_translation(Vec3::X * 2.0),
+            ],
+        }
+        .collider_builder()
+        .unwrap()
+        .build();
+
+        let compound = collider
+            .shape()
+            .as_compound()
+            .expect("Created shape was not a compound");
+
+        assert_eq!(compound.shapes().len(), 2);
+        let (position, shape) = &compound.shapes()[1];
+        assert_eq!(position.translation.x, 2.0);
+        assert!(shape.as_ball().is_some());
+    }
+
+    #[test]
+    fn build_trimesh_with_triangles_out_of_bounds() {
+        let result = CollisionShape::TriMesh {
+            vertices: vec![Vec3::ZERO, Vec3::X, Vec3::Y],
+            indices: vec![[0, 1, 2], [0, 1, 3]],
+        }
+        .collider_builder();
+
+        assert_eq!(result.err(), Some(CollisionShapeError::IndexOutOfBounds));
+    }
+
+    #[test]
+    fn build_empty_polyline() {
+        let result = CollisionShape::Polyline {
+            vertices: vec![Vec3::ZERO],
+            indices: None,
+        }
+        .collider_builder();
+
+        assert_eq!(result.err(), Some(CollisionShapeError::EmptyShape));
+    }
+
+    #[test]
+    fn build_compound_with_composite_children() {
+        let sphere = CompoundShapeChild::new(CollisionShape::Sphere { radius: 1.0 });
+        let result = CollisionShape::Compound {
+            shapes: vec![
+                sphere.clone(),
+                CompoundShapeChild::new(CollisionShape::Compound {
+                    shapes: vec![sphere],
+                }),
+            ],
+        }
+        .collider_builder();
+
+        assert_eq!(result.err(), Some(CollisionShapeError::NestedCompound));
+    }
+
+    #[test]
+    fn build_empty_compound() {
+        let result = CollisionShape::Compound { shapes: Vec::new() }.collider_builder();
+
+        assert_eq!(result.err(), Some(CollisionShapeError::EmptyShape));
+    }
+
     #[test]
     fn build_custom_collider_builder() {
         let collider = CollisionShape::Custom {
             shape: CustomCollisionShape::new(ColliderBuilder::ball(4.2)),
         }
         .collider_builder()
+        .unwrap()
         .build();
 
         let ball = collider
@@ -450,13 +1079,56 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(
-        expected = "Unsupported custom collision shape is used: CustomCollisionShape(())"
-    )]
     fn build_custom_unsupported() {
-        let _ = CollisionShape::Custom {
+        let result = CollisionShape::Custom {
             shape: CustomCollisionShape::new(()),
         }
         .collider_builder();
+
+        assert_eq!(
+            result.err(),
+            Some(CollisionShapeError::UnsupportedCustomShape("()"))
+        );
+    }
+
+    #[test]
+    fn build_negative_sphere() {
+        let result = CollisionShape::Sphere { radius: -1.0 }.collider_builder();
+
+        assert_eq!(result.err(), Some(CollisionShapeError::InvalidDimension));
+    }
+
+    #[test]
+    fn build_degenerate_convex_hull() {
+        let result = CollisionShape::ConvexHull {
+            points: vec![Vec3::ZERO, Vec3::X],
+            border_radius: None,
+        }
+        .collider_builder();
+
+        assert_eq!(result.err(), Some(CollisionShapeError::InvalidConvexHull));
+    }
+
+    #[test]
+    fn build_empty_heightfield() {
+        let result = CollisionShape::HeightField {
+            size: Vec2::new(2.0, 1.0),
+            heights: vec![vec![]],
+        }
+        .collider_builder();
+
+        assert_eq!(result.err(), Some(CollisionShapeError::InvalidHeightField));
+    }
+
+    #[test]
+    fn build_compound_with_invalid_child() {
+        let result = CollisionShape::Compound {
+            shapes: vec![CompoundShapeChild::new(CollisionShape::Sphere {
+                radius: f32::NAN,
+            })],
+        }
+        .collider_builder();
+
+        assert_eq!(result.err(), Some(CollisionShapeError::InvalidDimension));
     }
 }
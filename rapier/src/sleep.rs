@@ -0,0 +1,120 @@
+use bevy::app::Events;
+use bevy::ecs::prelude::*;
+use fnv::FnvHashSet;
+
+use heron_core::{SleepEvent, Sleeping};
+
+use crate::rapier::dynamics::{IslandManager, RigidBody, RigidBodyActivation, RigidBodySet};
+
+pub(crate) fn update_rapier_sleeping(
+    mut bodies: ResMut<'_, RigidBodySet>,
+    handles: Res<'_, super::body::HandleMap>,
+    query: Query<'_, '_, (Entity, &Sleeping), (Changed<Sleeping>, With<super::RigidBodyHandle>)>,
+) {
+    for (entity, sleeping) in query.iter() {
+        if let Some(body) = handles
+            .get(&entity)
+            .and_then(|handle| bodies.get_mut(*handle))
+        {
+            set_threshold(body, *sleeping);
+
+            if sleeping.sleeping && sleeping.can_sleep() && !body.is_sleeping() {
+                body.sleep();
+            } else if !sleeping.sleeping && body.is_sleeping() {
+                body.wake_up(true);
+            }
+        }
+    }
+}
+
+pub(crate) fn set_threshold(body: &mut RigidBody, sleeping: Sleeping) {
+    body.activation_mut().threshold = sleeping.threshold;
+}
+
+pub(crate) fn reset_rapier_sleeping(
+    mut bodies: ResMut<'_, RigidBodySet>,
+    handles: Res<'_, super::body::HandleMap>,
+    removed: RemovedComponents<'_, Sleeping>,
+) {
+    removed
+        .iter()
+        .filter_map(|entity| handles.get(&entity))
+        .for_each(|handle| {
+            if let Some(body) = bodies.get_mut(*handle) {
+                body.activation_mut().threshold = RigidBodyActivation::default_threshold();
+            }
+        });
+}
+
+/// Rapier wakes up the new bodies during their first step. The ones that should start asleep are
+/// therefore put to sleep right after it.
+pub(crate) fn update_sleeping_component(
+    mut bodies: ResMut<'_, RigidBodySet>,
+    handles: Res<'_, super::body::HandleMap>,
+    mut query: Query<
+        '_,
+        '_,
+        (
+            Entity,
+            ChangeTrackers<super::RigidBodyHandle>,
+            &mut Sleeping,
+        ),
+    >,
+) {
+    for (entity, handle_tracker, mut sleeping) in query.iter_mut() {
+        if let Some(body) = handles
+            .get(&entity)
+            .and_then(|handle| bodies.get_mut(*handle))
+        {
+            if handle_tracker.is_added() && sleeping.sleeping && sleeping.can_sleep() {
+                body.sleep();
+            } else if sleeping.sleeping != body.is_sleeping() {
+                sleeping.sleeping = body.is_sleeping();
+            }
+        }
+    }
+}
+
+/// Compare the awake bodies with the ones of the previous step
+///
+/// Only the bodies that are awake (now or at the previous step) are visited. The new bodies are
+/// always awake after their first step, and don't fire any event.
+pub(crate) fn fire_sleep_events(
+    bodies: Res<'_, RigidBodySet>,
+    islands: Res<'_, IslandManager>,
+    handles: Res<'_, super::body::HandleMap>,
+    new_bodies: Query<'_, '_, (), Added<super::RigidBodyHandle>>,
+    mut awake: Local<'_, FnvHashSet<Entity>>,
+    mut events: ResMut<'_, Events<SleepEvent>>,
+) {
+    let now_awake: FnvHashSet<Entity> = islands
+        .active_dynamic_bodies()
+        .iter()
+        .filter_map(|handle| bodies.get(*handle))
+        .map(entity)
+        .collect();
+
+    for entity in awake.difference(&now_awake) {
+        let is_sleeping = handles
+            .get(entity)
+            .and_then(|handle| bodies.get(*handle))
+            .map_or(false, RigidBody::is_sleeping);
+
+        if is_sleeping {
+            events.send(SleepEvent::FellAsleep(*entity));
+        }
+    }
+
+    for entity in now_awake.difference(&awake) {
+        if new_bodies.get(*entity).is_err() {
+            events.send(SleepEvent::WokeUp(*entity));
+        }
+    }
+
+    *awake = now_awake;
+}
+
+fn entity(body: &RigidBody) -> Entity {
+    #[allow(clippy::cast_possible_truncation)]
+    Entity::from_bits(body.user_data as u64)
+}
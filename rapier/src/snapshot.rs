@@ -0,0 +1,223 @@
+use bevy::ecs::entity::EntityMap;
+use bevy::prelude::*;
+use fnv::FnvHashMap;
+use heron_core::PhysicsWorldId;
+use serde::{Deserialize, Serialize};
+
+use crate::rapier::dynamics::{self, CCDSolver, IslandManager, JointSet, RigidBodySet};
+use crate::rapier::geometry::{self, BroadPhase, ColliderSet, NarrowPhase};
+use crate::rapier::pipeline::QueryPipeline;
+use crate::{body, joint, shape, PhysicsWorlds};
+
+/// Serializable snapshot of the whole physics simulation
+///
+/// It contains the state of every rigid body, collider and joint, including the resting contacts,
+/// sleep states and warm-starting data. Restoring a snapshot resumes the simulation exactly where
+/// it was captured, which is not the case when re-creating the bodies from their components.
+///
+/// Requires the `serde-2d` or `serde-3d` feature.
+///
+/// The rapier world contains maps whose keys are not strings. It can therefore not be serialized
+/// to JSON, but formats such as [RON](https://github.com/ron-rs/ron) (used by bevy scenes) work.
+///
+/// # Restoring
+///
+/// The snapshot only contains the physics world. The entities holding the heron components
+/// ([`RigidBody`](heron_core::RigidBody), [`CollisionShape`](heron_core::CollisionShape), etc.)
+/// must be restored separately (e.g. with a bevy scene).
+///
+/// The snapshot should be restored once those entities have been processed by heron at least
+/// once. Otherwise heron would consider their components as new, and re-create their bodies from
+/// scratch.
+///
+/// If the entities have been re-spawned with different ids, use
+/// [`restore_with_entity_map`](Self::restore_with_entity_map).
+///
+/// Only the default physics world is captured (see [`PhysicsWorlds`](crate::PhysicsWorlds)).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_rapier::PhysicsSnapshot;
+/// fn save(world: &mut World) {
+///     let snapshot = PhysicsSnapshot::capture(world);
+///     // Serialize the snapshot, for example with RON...
+/// }
+///
+/// fn load(world: &mut World) {
+///     let snapshot: PhysicsSnapshot = todo!("Deserialize the snapshot");
+///     snapshot.restore(world);
+/// }
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PhysicsSnapshot {
+    bodies: RigidBodySet,
+    colliders: ColliderSet,
+    joints: JointSet,
+    islands: IslandManager,
+    broad_phase: BroadPhase,
+    narrow_phase: NarrowPhase,
+    ccd_solver: CCDSolver,
+    body_handles: Vec<(u64, dynamics::RigidBodyHandle)>,
+    collider_handles: Vec<(u64, geometry::ColliderHandle)>,
+    joint_handles: Vec<(u64, dynamics::JointHandle)>,
+}
+
+impl PhysicsSnapshot {
+    /// Capture the current state of the physics world
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`RapierPlugin`](crate::RapierPlugin) is not installed
+    #[must_use]
+    pub fn capture(world: &World) -> Self {
+        Self {
+            bodies: world.get_resource::<RigidBodySet>().unwrap().clone(),
+            colliders: world.get_resource::<ColliderSet>().unwrap().clone(),
+            joints: world.get_resource::<JointSet>().unwrap().clone(),
+            islands: world.get_resource::<IslandManager>().unwrap().clone(),
+            broad_phase: world.get_resource::<BroadPhase>().unwrap().clone(),
+            narrow_phase: world.get_resource::<NarrowPhase>().unwrap().clone(),
+            ccd_solver: world.get_resource::<CCDSolver>().unwrap().clone(),
+            body_handles: to_bits(world.get_resource::<body::HandleMap>().unwrap()),
+            collider_handles: to_bits(world.get_resource::<shape::HandleMap>().unwrap()),
+            joint_handles: to_bits(world.get_resource::<joint::HandleMap>().unwrap()),
+        }
+    }
+
+    /// Replace the physics world by this snapshot
+    ///
+    /// The [`RigidBodyHandle`](crate::RigidBodyHandle), [`ColliderHandle`](crate::ColliderHandle)
+    /// and [`JointHandle`](crate::JointHandle) components are re-inserted on the entities of the
+    /// snapshot, and their `Transform` and [`Velocity`](heron_core::Velocity) are updated to match
+    /// the restored bodies.
+    ///
+    /// The handles are removed from the entities absent from the snapshot, so that heron creates
+    /// their bodies, colliders and joints again during the next update.
+    pub fn restore(self, world: &mut World) {
+        self.restore_with(world, |entity| entity);
+    }
+
+    /// Replace the physics world by this snapshot, after mapping the entities of the snapshot
+    ///
+    /// Entities absent from the map are kept unchanged.
+    pub fn restore_with_entity_map(self, world: &mut World, entity_map: &EntityMap) {
+        self.restore_with(world, |entity| entity_map.get(entity).unwrap_or(entity));
+    }
+
+    fn restore_with(self, world: &mut World, map: impl Fn(Entity) -> Entity) {
+        let Self {
+            mut bodies,
+            mut colliders,
+            joints,
+            islands,
+            broad_phase,
+            narrow_phase,
+            ccd_solver,
+            body_handles,
+            collider_handles,
+            joint_handles,
+        } = self;
+
+        for (_, body) in bodies.iter_mut() {
+            body.user_data = map_user_data(body.user_data, &map);
+        }
+
+        for (_, collider) in colliders.iter_mut() {
+            collider.user_data = map_user_data(collider.user_data, &map);
+        }
+
+        let body_handles = from_bits(body_handles, &map);
+        let collider_handles = from_bits(collider_handles, &map);
+        let joint_handles = from_bits(joint_handles, &map);
+
+        replace_handles(world, &body_handles, crate::RigidBodyHandle);
+        replace_handles(world, &collider_handles, crate::ColliderHandle);
+        replace_handles(world, &joint_handles, crate::JointHandle);
+
+        let mut query_pipeline = QueryPipeline::new();
+        query_pipeline.update(&islands, &bodies, &colliders);
+
+        world.insert_resource(bodies);
+        world.insert_resource(colliders);
+        world.insert_resource(joints);
+        world.insert_resource(islands);
+        world.insert_resource(broad_phase);
+        world.insert_resource(narrow_phase);
+        world.insert_resource(ccd_solver);
+        world.insert_resource(query_pipeline);
+        world.insert_resource(body_handles);
+        world.insert_resource(collider_handles);
+        world.insert_resource(joint_handles);
+
+        // Reflect the restored state in the bevy components, so that it isn't overwritten by the
+        // outdated components during the next update
+        run_system(world, crate::body::update_bevy_transform);
+        run_system(world, crate::velocity::update_velocity_component);
+    }
+}
+
+/// Insert the handles of the snapshot, and remove the handles of the entities absent from the
+/// snapshot, so that heron creates their bodies, colliders or joints again
+///
+/// The entities of the additional physics worlds are left untouched, as the snapshot only concerns
+/// the default world.
+fn replace_handles<H: Copy, C: Component>(
+    world: &mut World,
+    handles: &FnvHashMap<Entity, H>,
+    component: impl Fn(H) -> C,
+) {
+    let candidates: Vec<(Entity, Option<PhysicsWorldId>)> = world
+        .query_filtered::<(Entity, Option<&PhysicsWorldId>), With<C>>()
+        .iter(world)
+        .map(|(entity, world_id)| (entity, world_id.copied()))
+        .collect();
+
+    let worlds = world.get_resource::<PhysicsWorlds>();
+    let stale: Vec<Entity> = candidates
+        .into_iter()
+        .filter(|(entity, world_id)| {
+            world_id.map_or(true, |id| id == PhysicsWorldId::DEFAULT)
+                && !worlds.map_or(false, |worlds| worlds.has_entity(*entity))
+                && !handles.contains_key(entity)
+        })
+        .map(|(entity, _)| entity)
+        .collect();
+
+    for entity in stale {
+        world.entity_mut(entity).remove::<C>();
+    }
+
+    for (entity, handle) in handles {
+        if let Some(mut entity) = world.get_entity_mut(*entity) {
+            entity.insert(component(*handle));
+        }
+    }
+}
+
+fn run_system<Params>(world: &mut World, system: impl IntoSystem<(), (), Params>) {
+    let mut system = system.system();
+    system.initialize(world);
+    system.run((), world);
+}
+
+fn to_bits<H: Copy>(handles: &FnvHashMap<Entity, H>) -> Vec<(u64, H)> {
+    handles
+        .iter()
+        .map(|(entity, handle)| (entity.to_bits(), *handle))
+        .collect()
+}
+
+fn from_bits<H>(handles: Vec<(u64, H)>, map: impl Fn(Entity) -> Entity) -> FnvHashMap<Entity, H> {
+    handles
+        .into_iter()
+        .map(|(bits, handle)| (map(Entity::from_bits(bits)), handle))
+        .collect()
+}
+
+fn map_user_data(user_data: u128, map: impl Fn(Entity) -> Entity) -> u128 {
+    #[allow(clippy::cast_possible_truncation)]
+    let entity = Entity::from_bits(user_data as u64);
+    map(entity).to_bits().into()
+}
@@ -0,0 +1,228 @@
+use bevy::prelude::*;
+
+use heron_core::{Gravity, PhysicsSteps, PhysicsTime, PhysicsWorldId};
+
+use crate::rapier::dynamics::{
+    CCDSolver, IntegrationParameters, IslandManager, JointSet, RigidBodySet,
+};
+use crate::rapier::geometry::{BroadPhase, ColliderSet, NarrowPhase};
+use crate::rapier::pipeline::{PhysicsPipeline, QueryPipeline};
+use crate::{body, joint, shape};
+
+/// Resource containing the additional physics worlds, simulated independently of the default one
+///
+/// The rigid bodies are assigned to a world with the [`PhysicsWorldId`] component. The bodies
+/// without it belong to the default world, which is configured by the app resources
+/// ([`Gravity`], [`PhysicsSteps`] and [`PhysicsTime`]). Each additional world has its own
+/// [`PhysicsWorldSettings`] instead.
+///
+/// The additional worlds are stepped at the end of the bevy `CoreStage::PostUpdate` stage, after
+/// the default world. They fire the same events as the default world
+/// ([`CollisionEvent`](heron_core::CollisionEvent), etc.).
+///
+/// The [`PhysicsWorld`](crate::PhysicsWorld) system parameter and the
+/// [`PhysicsSnapshot`](crate::PhysicsSnapshot) only concern the default world.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// # use heron_rapier::PhysicsWorlds;
+/// const PREVIEW_WORLD: PhysicsWorldId = PhysicsWorldId(1);
+///
+/// fn setup(mut worlds: ResMut<PhysicsWorlds>) {
+///     worlds.add(PREVIEW_WORLD).gravity = Gravity::from(Vec3::Y * -9.81);
+/// }
+///
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_bundle(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .insert(RigidBody::Dynamic)
+///         .insert(CollisionShape::Sphere { radius: 1.0 })
+///         .insert(PREVIEW_WORLD);
+/// }
+/// ```
+#[derive(Default)]
+pub struct PhysicsWorlds {
+    worlds: Vec<WorldState>,
+}
+
+/// Settings of an additional physics world
+///
+/// They replace the app resources of the same types, which only concern the default world.
+#[derive(Debug, Clone, Default)]
+pub struct PhysicsWorldSettings {
+    /// Gravity of the world
+    pub gravity: Gravity,
+
+    /// Frequency of the physics steps of the world
+    pub steps: PhysicsSteps,
+
+    /// Time scale of the world
+    pub time: PhysicsTime,
+}
+
+/// Id of the physics world whose state is currently installed in the app resources
+#[derive(Debug, Copy, Clone, Default)]
+pub(crate) struct ActiveWorld(pub(crate) PhysicsWorldId);
+
+impl ActiveWorld {
+    /// Returns true if the entity having the given (optional) world id belongs to the active world
+    pub(crate) fn contains(self, world: Option<&PhysicsWorldId>) -> bool {
+        world.copied().unwrap_or_default() == self.0
+    }
+}
+
+impl PhysicsWorlds {
+    /// Register a new physics world, and returns its settings
+    ///
+    /// If the world is already registered, its current settings are returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the id is [`PhysicsWorldId::DEFAULT`]. The default world is always registered,
+    /// and configured by the app resources.
+    pub fn add(&mut self, id: PhysicsWorldId) -> &mut PhysicsWorldSettings {
+        assert_ne!(
+            id,
+            PhysicsWorldId::DEFAULT,
+            "The default physics world cannot be added"
+        );
+
+        let index = match self.position(id) {
+            Some(index) => index,
+            None => {
+                self.worlds.push(WorldState::new(id));
+                self.worlds.len() - 1
+            }
+        };
+
+        &mut self.worlds[index].settings
+    }
+
+    /// Returns true if the world is registered
+    #[must_use]
+    pub fn contains(&self, id: PhysicsWorldId) -> bool {
+        self.position(id).is_some()
+    }
+
+    /// Returns the settings of the given world, if it is registered
+    #[must_use]
+    pub fn settings(&self, id: PhysicsWorldId) -> Option<&PhysicsWorldSettings> {
+        self.position(id).map(|index| &self.worlds[index].settings)
+    }
+
+    /// Returns the mutable settings of the given world, if it is registered
+    pub fn settings_mut(&mut self, id: PhysicsWorldId) -> Option<&mut PhysicsWorldSettings> {
+        self.position(id)
+            .map(move |index| &mut self.worlds[index].settings)
+    }
+
+    /// Iterate over the ids of the registered worlds (excluding the default one)
+    #[must_use]
+    pub fn ids(&self) -> impl Iterator<Item = PhysicsWorldId> + '_ {
+        self.worlds.iter().map(|world| world.active.0)
+    }
+
+    /// Returns true if the entity has a body, collider or joint in one of the additional worlds
+    #[cfg(snapshot)]
+    pub(crate) fn has_entity(&self, entity: Entity) -> bool {
+        self.worlds.iter().any(|world| {
+            world.body_handles.contains_key(&entity)
+                || world.collider_handles.contains_key(&entity)
+                || world.joint_handles.contains_key(&entity)
+        })
+    }
+
+    fn position(&self, id: PhysicsWorldId) -> Option<usize> {
+        self.worlds.iter().position(|world| world.active.0 == id)
+    }
+}
+
+/// State of an additional physics world
+///
+/// While the world is stepped, its state is swapped with the one of the default world in the app
+/// resources. That way, the same systems can simulate all the worlds.
+struct WorldState {
+    active: ActiveWorld,
+    settings: PhysicsWorldSettings,
+    pipeline: PhysicsPipeline,
+    integration_parameters: IntegrationParameters,
+    broad_phase: BroadPhase,
+    narrow_phase: NarrowPhase,
+    bodies: RigidBodySet,
+    colliders: ColliderSet,
+    joints: JointSet,
+    islands: IslandManager,
+    ccd_solver: CCDSolver,
+    query_pipeline: QueryPipeline,
+    body_handles: body::HandleMap,
+    collider_handles: shape::HandleMap,
+    joint_handles: joint::HandleMap,
+
+    /// The systems must not be shared between the worlds, as they keep track of the changes they
+    /// have already seen
+    schedule: Schedule,
+}
+
+impl WorldState {
+    fn new(id: PhysicsWorldId) -> Self {
+        Self {
+            active: ActiveWorld(id),
+            settings: PhysicsWorldSettings::default(),
+            pipeline: PhysicsPipeline::new(),
+            integration_parameters: IntegrationParameters::default(),
+            broad_phase: BroadPhase::new(),
+            narrow_phase: NarrowPhase::new(),
+            bodies: RigidBodySet::new(),
+            colliders: ColliderSet::new(),
+            joints: JointSet::new(),
+            islands: IslandManager::new(),
+            ccd_solver: CCDSolver::new(),
+            query_pipeline: QueryPipeline::new(),
+            body_handles: body::HandleMap::default(),
+            collider_handles: shape::HandleMap::default(),
+            joint_handles: joint::HandleMap::default(),
+            schedule: crate::world_schedule(),
+        }
+    }
+
+    /// Swap the state of this world with the one installed in the app resources
+    fn swap(&mut self, world: &mut World) {
+        swap_resource(world, &mut self.active);
+        swap_resource(world, &mut self.settings.gravity);
+        swap_resource(world, &mut self.settings.steps);
+        swap_resource(world, &mut self.settings.time);
+        swap_resource(world, &mut self.pipeline);
+        swap_resource(world, &mut self.integration_parameters);
+        swap_resource(world, &mut self.broad_phase);
+        swap_resource(world, &mut self.narrow_phase);
+        swap_resource(world, &mut self.bodies);
+        swap_resource(world, &mut self.colliders);
+        swap_resource(world, &mut self.joints);
+        swap_resource(world, &mut self.islands);
+        swap_resource(world, &mut self.ccd_solver);
+        swap_resource(world, &mut self.query_pipeline);
+        swap_resource(world, &mut self.body_handles);
+        swap_resource(world, &mut self.collider_handles);
+        swap_resource(world, &mut self.joint_handles);
+    }
+}
+
+fn swap_resource<T: Send + Sync + 'static>(world: &mut World, value: &mut T) {
+    let mut resource = world
+        .get_resource_mut::<T>()
+        .expect("The RapierPlugin is not installed");
+    std::mem::swap(&mut *resource, value);
+}
+
+/// Exclusive system that steps the additional physics worlds
+pub(crate) fn step_additional_worlds(world: &mut World) {
+    world.resource_scope(|world, mut worlds: Mut<'_, PhysicsWorlds>| {
+        for state in &mut worlds.worlds {
+            state.swap(world);
+            state.schedule.run(world);
+            state.swap(world);
+        }
+    });
+}
@@ -0,0 +1,85 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CollisionShape, ContinuousCollisionDetection, PhysicsSteps, RigidBody, Velocity};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    app
+}
+
+/// Spawn a thin wall at `x = 10` and a fast sphere moving toward it
+fn spawn_wall_and_bullet(app: &mut App) -> Entity {
+    let wall_position = Vec3::X * 10.0;
+    app.world.spawn().insert_bundle((
+        Transform::from_translation(wall_position),
+        GlobalTransform::from_translation(wall_position),
+        RigidBody::Static,
+        CollisionShape::Cuboid {
+            half_extends: Vec3::new(0.05, 10.0, 10.0),
+            border_radius: None,
+        },
+    ));
+
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 0.1 },
+            Velocity::from_linear(Vec3::X * 500.0),
+        ))
+        .id()
+}
+
+fn x_after_one_second(app: &mut App, bullet: Entity) -> f32 {
+    for _ in 0..60 {
+        app.update();
+    }
+    app.world.get::<Transform>(bullet).unwrap().translation.x
+}
+
+#[test]
+fn fast_sphere_tunnels_through_thin_wall_without_ccd() {
+    let mut app = test_app();
+    let bullet = spawn_wall_and_bullet(&mut app);
+
+    assert!(x_after_one_second(&mut app, bullet) > 10.0);
+}
+
+#[test]
+fn fast_sphere_is_stopped_by_thin_wall_with_ccd() {
+    let mut app = test_app();
+    let bullet = spawn_wall_and_bullet(&mut app);
+    app.world
+        .entity_mut(bullet)
+        .insert(ContinuousCollisionDetection);
+
+    assert!(x_after_one_second(&mut app, bullet) < 10.0);
+}
+
+#[test]
+fn ccd_can_be_enabled_at_runtime() {
+    let mut app = test_app();
+    let bullet = spawn_wall_and_bullet(&mut app);
+
+    app.update();
+    app.world
+        .entity_mut(bullet)
+        .insert(ContinuousCollisionDetection);
+
+    assert!(x_after_one_second(&mut app, bullet) < 10.0);
+}
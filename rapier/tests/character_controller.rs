@@ -0,0 +1,129 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CharacterController, CollisionShape, PhysicsSteps, RigidBody};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    app
+}
+
+fn spawn_static_cuboid(app: &mut App, translation: Vec3, half_extends: Vec3) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::from_translation(translation),
+            GlobalTransform::from_translation(translation),
+            RigidBody::Static,
+            CollisionShape::Cuboid {
+                half_extends,
+                border_radius: None,
+            },
+        ))
+        .id()
+}
+
+fn spawn_character(app: &mut App, translation: Vec3, controller: CharacterController) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::from_translation(translation),
+            GlobalTransform::from_translation(translation),
+            RigidBody::KinematicPositionBased,
+            CollisionShape::Sphere { radius: 1.0 },
+            controller,
+        ))
+        .id()
+}
+
+#[test]
+fn moves_freely_without_obstacle() {
+    let mut app = test_app();
+    let character = spawn_character(&mut app, Vec3::ZERO, CharacterController::default());
+
+    app.update();
+    app.world
+        .get_mut::<CharacterController>(character)
+        .unwrap()
+        .translation = Vec3::X * 10.0;
+    app.update();
+
+    let translation = app.world.get::<Transform>(character).unwrap().translation;
+    assert!(translation.distance(Vec3::X * 10.0) < 0.001);
+
+    let controller = app.world.get::<CharacterController>(character).unwrap();
+    assert_eq!(controller.translation, Vec3::ZERO);
+    assert!(!controller.is_grounded());
+    assert!(controller.touching().is_empty());
+}
+
+#[test]
+fn is_stopped_by_wall_and_grounded_on_floor() {
+    let mut app = test_app();
+    let floor = spawn_static_cuboid(&mut app, -Vec3::Y, Vec3::new(100.0, 1.0, 100.0));
+    let wall = spawn_static_cuboid(&mut app, Vec3::X * 5.0, Vec3::new(1.0, 10.0, 10.0));
+    let character = spawn_character(
+        &mut app,
+        Vec3::new(0.0, 1.01, 0.0),
+        CharacterController::default(),
+    );
+
+    app.update();
+    app.world
+        .get_mut::<CharacterController>(character)
+        .unwrap()
+        .translation = Vec3::X * 10.0;
+    app.update();
+
+    let translation = app.world.get::<Transform>(character).unwrap().translation;
+    assert!(translation.x < 3.0);
+    assert!(translation.x > 2.9);
+    assert!((translation.y - 1.01).abs() < 0.001);
+
+    let controller = app.world.get::<CharacterController>(character).unwrap();
+    assert!(controller.is_grounded());
+    assert!(controller.touching().contains(&wall));
+    assert!(!controller.touching().contains(&floor));
+}
+
+#[test]
+fn climbs_steps_lower_than_step_height() {
+    let mut app = test_app();
+    spawn_static_cuboid(&mut app, -Vec3::Y, Vec3::new(100.0, 1.0, 100.0));
+    spawn_static_cuboid(
+        &mut app,
+        Vec3::new(10.0, 0.25, 0.0),
+        Vec3::new(5.0, 0.25, 5.0),
+    );
+    let character = spawn_character(
+        &mut app,
+        Vec3::new(0.0, 1.01, 0.0),
+        CharacterController::default().with_step_height(0.6),
+    );
+
+    app.update();
+    app.world
+        .get_mut::<CharacterController>(character)
+        .unwrap()
+        .translation = Vec3::X * 8.0;
+    app.update();
+
+    let translation = app.world.get::<Transform>(character).unwrap().translation;
+    assert!(translation.x > 7.0);
+    assert!(translation.y > 1.4);
+    assert!(app
+        .world
+        .get::<CharacterController>(character)
+        .unwrap()
+        .is_grounded());
+}
@@ -0,0 +1,131 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CollisionShape, Collisions, Gravity, PhysicsSteps, RigidBody};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    app
+}
+
+fn spawn(app: &mut App, body: RigidBody, shape: CollisionShape, translation: Vec3) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::from_translation(translation),
+            GlobalTransform::default(),
+            body,
+            shape,
+            Collisions::default(),
+        ))
+        .id()
+}
+
+fn collisions(app: &App, entity: Entity) -> &Collisions {
+    app.world.get::<Collisions>(entity).unwrap()
+}
+
+#[test]
+fn bodies_in_contact_list_each_other() {
+    let mut app = test_app();
+    app.insert_resource(Gravity::from(Vec3::Y * -10.0));
+
+    let floor = spawn(
+        &mut app,
+        RigidBody::Static,
+        CollisionShape::Cuboid {
+            half_extends: Vec3::new(10.0, 1.0, 10.0),
+            border_radius: None,
+        },
+        Vec3::ZERO,
+    );
+    let ball = spawn(
+        &mut app,
+        RigidBody::Dynamic,
+        CollisionShape::Sphere { radius: 1.0 },
+        Vec3::Y * 2.5,
+    );
+
+    for _ in 0..30 {
+        app.update();
+    }
+
+    assert!(collisions(&app, floor).contains(ball));
+    assert!(collisions(&app, ball).contains(floor));
+    assert_eq!(
+        collisions(&app, ball)
+            .rigid_body_entities()
+            .collect::<Vec<_>>(),
+        vec![floor]
+    );
+}
+
+#[test]
+fn intersections_are_listed_until_the_shapes_separate() {
+    let mut app = test_app();
+
+    let sensor = spawn(
+        &mut app,
+        RigidBody::Sensor,
+        CollisionShape::Sphere { radius: 1.0 },
+        Vec3::ZERO,
+    );
+    let other = spawn(
+        &mut app,
+        RigidBody::KinematicPositionBased,
+        CollisionShape::Sphere { radius: 1.0 },
+        Vec3::X,
+    );
+
+    app.update();
+    app.update();
+
+    assert!(collisions(&app, sensor).contains(other));
+    assert_eq!(collisions(&app, sensor).len(), 1);
+
+    app.world.get_mut::<Transform>(other).unwrap().translation = Vec3::X * 100.0;
+    app.update();
+    app.update();
+
+    assert!(collisions(&app, sensor).is_empty());
+    assert!(collisions(&app, other).is_empty());
+}
+
+#[test]
+fn shapes_of_the_same_body_are_ignored() {
+    let mut app = test_app();
+
+    let child = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+    let body = spawn(
+        &mut app,
+        RigidBody::Sensor,
+        CollisionShape::Sphere { radius: 1.0 },
+        Vec3::ZERO,
+    );
+    app.world.entity_mut(body).push_children(&[child]);
+
+    app.update();
+    app.update();
+
+    assert!(collisions(&app, body).is_empty());
+}
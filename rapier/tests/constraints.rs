@@ -6,7 +6,10 @@ use bevy::core::CorePlugin;
 use bevy::prelude::*;
 use bevy::reflect::TypeRegistryArc;
 
-use heron_core::{CollisionShape, PhysicsSteps, RigidBody, RotationConstraints};
+use heron_core::{
+    CollisionShape, Gravity, PhysicsSteps, RigidBody, RotationConstraints, TranslationConstraints,
+    Velocity,
+};
 use heron_rapier::convert::IntoRapier;
 use heron_rapier::RapierPlugin;
 use utils::*;
@@ -162,3 +165,129 @@ fn rotation_is_unlocked_if_component_is_removed() {
         false
     );
 }
+
+#[test]
+fn translation_can_be_locked_at_creation() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 10.0 },
+            TranslationConstraints::lock(),
+        ))
+        .id();
+
+    app.update();
+
+    let bodies = app.world.get_resource::<RigidBodySet>().unwrap();
+
+    assert!(bodies
+        .get(
+            app.world
+                .get::<heron_rapier::RigidBodyHandle>(entity)
+                .unwrap()
+                .into_rapier()
+        )
+        .unwrap()
+        .is_translation_locked());
+}
+
+#[test]
+fn translation_is_unlocked_if_component_is_removed() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 10.0 },
+            TranslationConstraints::lock(),
+        ))
+        .id();
+
+    app.update();
+
+    app.world
+        .entity_mut(entity)
+        .remove::<TranslationConstraints>();
+
+    app.update();
+
+    let bodies = app.world.get_resource::<RigidBodySet>().unwrap();
+
+    assert!(!bodies
+        .get(
+            app.world
+                .get::<heron_rapier::RigidBodyHandle>(entity)
+                .unwrap()
+                .into_rapier()
+        )
+        .unwrap()
+        .is_translation_locked());
+}
+
+#[test]
+fn translation_constraints_are_applied_again_when_changed() {
+    let mut app = test_app();
+    app.insert_resource(Gravity::from(Vec3::Y * -10.0));
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 10.0 },
+            TranslationConstraints::restrict_to_x_only(),
+            Velocity::default(),
+        ))
+        .id();
+
+    app.update();
+    app.update();
+
+    assert!(app.world.get::<Velocity>(entity).unwrap().linear.y.abs() < 0.001);
+
+    app.world
+        .get_mut::<TranslationConstraints>(entity)
+        .unwrap()
+        .allow_y = true;
+
+    app.update();
+    app.update();
+
+    assert!(app.world.get::<Velocity>(entity).unwrap().linear.y < -1.0);
+}
+
+#[test]
+fn locked_axes_are_not_moved_by_forces() {
+    let mut app = test_app();
+    app.insert_resource(Gravity::from(Vec3::new(5.0, -10.0, 0.0)));
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 10.0 },
+            TranslationConstraints::restrict_to_x_only(),
+        ))
+        .id();
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let translation = app.world.get::<Transform>(entity).unwrap().translation;
+    assert!(translation.x > 1.0);
+    assert!(translation.y.abs() < 0.001);
+}
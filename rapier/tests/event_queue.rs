@@ -0,0 +1,101 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{
+    ActiveCollisionEvents, CollisionEventQueue, CollisionShape, EntityCollisionEvent, PhysicsSteps,
+    RigidBody,
+};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    app
+}
+
+fn spawn_sensor(app: &mut App, translation: Vec3) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::from_translation(translation),
+            GlobalTransform::default(),
+            RigidBody::Sensor,
+            CollisionShape::Sphere { radius: 1.0 },
+            CollisionEventQueue::default(),
+        ))
+        .id()
+}
+
+fn drain(app: &mut App, entity: Entity) -> Vec<EntityCollisionEvent> {
+    app.world
+        .get_mut::<CollisionEventQueue>(entity)
+        .unwrap()
+        .drain()
+        .collect()
+}
+
+#[test]
+fn each_entity_receives_its_own_events() {
+    let mut app = test_app();
+    let entity1 = spawn_sensor(&mut app, Vec3::ZERO);
+    let entity2 = spawn_sensor(&mut app, Vec3::X);
+    let unrelated = spawn_sensor(&mut app, Vec3::X * 100.0);
+    app.world
+        .entity_mut(entity1)
+        .insert(ActiveCollisionEvents::default());
+
+    app.update();
+
+    let events1 = drain(&mut app, entity1);
+    let events2 = drain(&mut app, entity2);
+
+    assert_eq!(events1.len(), 1);
+    assert!(events1[0].is_started());
+    assert_eq!(events1[0].this().rigid_body_entity(), entity1);
+    assert_eq!(events1[0].other().rigid_body_entity(), entity2);
+
+    assert_eq!(events2.len(), 1);
+    assert_eq!(events2[0].this().rigid_body_entity(), entity2);
+    assert_eq!(events2[0].other().rigid_body_entity(), entity1);
+
+    assert!(drain(&mut app, unrelated).is_empty());
+}
+
+#[test]
+fn events_stay_in_the_queue_until_drained() {
+    let mut app = test_app();
+    let entity1 = spawn_sensor(&mut app, Vec3::ZERO);
+    let entity2 = spawn_sensor(&mut app, Vec3::X);
+    app.world
+        .entity_mut(entity2)
+        .insert(ActiveCollisionEvents::default());
+
+    app.update();
+    app.world.get_mut::<Transform>(entity2).unwrap().translation = Vec3::X * 100.0;
+    app.update();
+    app.update();
+
+    let events = drain(&mut app, entity1);
+
+    assert_eq!(events.len(), 2);
+    assert!(events[0].is_started());
+    assert!(events[1].is_stopped());
+    assert!(events
+        .iter()
+        .all(|event| event.other().collision_shape_entity() == entity2));
+    assert!(app
+        .world
+        .get::<CollisionEventQueue>(entity1)
+        .unwrap()
+        .is_empty());
+}
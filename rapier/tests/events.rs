@@ -8,7 +8,10 @@ use bevy::prelude::*;
 use bevy::reflect::TypeRegistryArc;
 use rstest::*;
 
-use heron_core::{CollisionEvent, CollisionShape, PhysicsSteps, RigidBody, Velocity};
+use heron_core::{
+    ActiveCollisionEvents, CollisionContactEvent, CollisionEvent, CollisionShape,
+    CompoundShapeChild, PhysicsSteps, RigidBody, Velocity,
+};
 use heron_rapier::RapierPlugin;
 use utils::*;
 
@@ -48,6 +51,7 @@ fn collision_events_are_fired(#[case] type1: RigidBody, #[case] type2: RigidBody
             GlobalTransform::default(),
             CollisionShape::Sphere { radius: 10.0 },
             type1,
+            ActiveCollisionEvents::default(),
         ))
         .id();
 
@@ -103,6 +107,134 @@ fn collision_events_are_fired(#[case] type1: RigidBody, #[case] type2: RigidBody
     assert_eq!(events[1].collision_shape_entities(), (entity1, entity2));
 }
 
+#[test]
+fn contact_events_contain_the_impulses_of_the_solver() {
+    let mut app = test_app();
+
+    let ground = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Static,
+            CollisionShape::Cuboid {
+                half_extends: Vec3::new(10.0, 1.0, 10.0),
+                border_radius: None,
+            },
+        ))
+        .id();
+
+    let ball = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::from_translation(Vec3::Y * 2.0),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::from_linear(Vec3::Y * -10.0),
+            ActiveCollisionEvents::contacts(),
+        ))
+        .id();
+
+    let mut event_reader = app
+        .world
+        .get_resource::<Events<CollisionContactEvent>>()
+        .unwrap()
+        .get_reader();
+
+    app.update();
+
+    let events = app
+        .world
+        .get_resource::<Events<CollisionContactEvent>>()
+        .unwrap();
+    let events: Vec<CollisionContactEvent> = event_reader.iter(&events).cloned().collect();
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].rigid_body_entities(), (ground, ball));
+    assert!(events[0].total_impulse() > 0.0);
+}
+
+#[test]
+fn contact_points_are_positioned_on_the_child_of_compound_shapes() {
+    let mut app = test_app();
+
+    app.world.spawn().insert_bundle((
+        Transform::default(),
+        GlobalTransform::default(),
+        RigidBody::Static,
+        CollisionShape::Compound {
+            shapes: vec![CompoundShapeChild::new(CollisionShape::Cuboid {
+                half_extends: Vec3::ONE,
+                border_radius: None,
+            })
+            .with_translation(Vec3::X * 10.0)],
+        },
+    ));
+
+    app.world.spawn().insert_bundle((
+        Transform::from_translation(Vec3::new(10.0, 1.9, 0.0)),
+        GlobalTransform::default(),
+        RigidBody::Dynamic,
+        CollisionShape::Sphere { radius: 1.0 },
+        ActiveCollisionEvents::contacts(),
+    ));
+
+    let mut event_reader = app
+        .world
+        .get_resource::<Events<CollisionContactEvent>>()
+        .unwrap()
+        .get_reader();
+
+    app.update();
+
+    let events = app
+        .world
+        .get_resource::<Events<CollisionContactEvent>>()
+        .unwrap();
+    let events: Vec<CollisionContactEvent> = event_reader.iter(&events).cloned().collect();
+
+    assert_eq!(events.len(), 1);
+    assert!(!events[0].points().is_empty());
+    for point in events[0].points() {
+        assert!((point.point.x - 10.0).abs() < 1.1, "{:?}", point);
+        assert!((point.point.y - 1.0).abs() < 0.2, "{:?}", point);
+        assert!(point.normal.y > 0.9, "{:?}", point);
+    }
+}
+
+#[rstest]
+#[case(None)]
+#[case(Some(ActiveCollisionEvents::intersections()))]
+fn collision_events_are_not_fired_unless_activated(#[case] events: Option<ActiveCollisionEvents>) {
+    let mut app = test_app();
+
+    for x in [0.0, 1.0] {
+        let mut entity = app.world.spawn();
+        entity.insert_bundle((
+            Transform::from_translation(Vec3::X * x),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+        ));
+        if let Some(events) = events {
+            entity.insert(events);
+        }
+    }
+
+    let mut event_reader = app
+        .world
+        .get_resource::<Events<CollisionEvent>>()
+        .unwrap()
+        .get_reader();
+
+    app.update();
+
+    assert!(collect_events(&app, &mut event_reader).is_empty());
+}
+
 fn collect_events(
     app: &App,
     reader: &mut ManualEventReader<CollisionEvent>,
@@ -6,9 +6,9 @@ use bevy::core::CorePlugin;
 use bevy::prelude::*;
 use bevy::reflect::TypeRegistryArc;
 
-use heron_core::{CollisionShape, PhysicMaterial, PhysicsSteps, RigidBody};
+use heron_core::{CollisionShape, CombineRule, PhysicMaterial, PhysicsSteps, RigidBody};
 use heron_rapier::convert::IntoRapier;
-use heron_rapier::{ColliderHandle, RapierPlugin};
+use heron_rapier::{ColliderHandle, RapierPlugin, RigidBodyHandle};
 use utils::*;
 
 mod utils;
@@ -93,3 +93,127 @@ fn friction_can_be_updated() {
 
     assert_eq!(friction, collider.friction())
 }
+
+#[test]
+fn friction_combine_rule_can_be_defined() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 10.0 },
+            PhysicMaterial {
+                friction_combine_rule: CombineRule::Max,
+                ..Default::default()
+            },
+        ))
+        .id();
+
+    app.update();
+
+    let colliders = app.world.get_resource::<ColliderSet>().unwrap();
+    let collider = colliders
+        .get(
+            app.world
+                .get::<ColliderHandle>(entity)
+                .unwrap()
+                .into_rapier(),
+        )
+        .unwrap();
+
+    assert_eq!(
+        collider.friction_combine_rule(),
+        CoefficientCombineRule::Max
+    );
+}
+
+#[test]
+fn child_shape_can_define_its_own_friction() {
+    let mut app = test_app();
+
+    let child = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            CollisionShape::Sphere { radius: 1.0 },
+            PhysicMaterial {
+                friction: 0.8,
+                ..Default::default()
+            },
+        ))
+        .id();
+    let sibling = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+    app.world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            PhysicMaterial {
+                friction: 0.2,
+                ..Default::default()
+            },
+        ))
+        .push_children(&[child, sibling]);
+
+    app.update();
+
+    let colliders = app.world.get_resource::<ColliderSet>().unwrap();
+    let friction = |entity| {
+        colliders
+            .get(
+                app.world
+                    .get::<ColliderHandle>(entity)
+                    .unwrap()
+                    .into_rapier(),
+            )
+            .unwrap()
+            .friction()
+    };
+
+    assert_eq!(friction(child), 0.8);
+    assert_eq!(friction(sibling), 0.2);
+}
+
+#[test]
+fn body_is_not_recreated_when_friction_changes() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 10.0 },
+        ))
+        .id();
+
+    app.update();
+    let body_handle = *app.world.get::<RigidBodyHandle>(entity).unwrap();
+    let collider_handle = *app.world.get::<ColliderHandle>(entity).unwrap();
+
+    app.world.entity_mut(entity).insert(PhysicMaterial {
+        friction: 0.3,
+        ..Default::default()
+    });
+    app.update();
+
+    assert_eq!(app.world.get::<RigidBodyHandle>(entity), Some(&body_handle));
+    assert_eq!(
+        app.world.get::<ColliderHandle>(entity),
+        Some(&collider_handle)
+    );
+}
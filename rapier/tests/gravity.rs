@@ -0,0 +1,126 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{
+    CollisionShape, Gravity, GravityScale, GravityZone, PhysicsSteps, RigidBody, Velocity,
+};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )))
+        .insert_resource(Gravity::from(Vec3::Y * -10.0))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    app
+}
+
+fn spawn_body(app: &mut App) -> Entity {
+    spawn_body_at(app, Vec3::ZERO)
+}
+
+fn spawn_body_at(app: &mut App, translation: Vec3) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::from_translation(translation),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::default(),
+        ))
+        .id()
+}
+
+fn spawn_zone(app: &mut App, zone: GravityZone) {
+    app.world.spawn().insert_bundle((
+        Transform::default(),
+        GlobalTransform::default(),
+        RigidBody::Sensor,
+        CollisionShape::Cuboid {
+            half_extends: Vec3::splat(100.0),
+            border_radius: None,
+        },
+        zone,
+    ));
+}
+
+fn run_for_half_a_second(app: &mut App) {
+    for _ in 0..30 {
+        app.update();
+    }
+}
+
+#[test]
+fn body_with_zero_gravity_scale_floats() {
+    let mut app = test_app();
+    let floating = spawn_body(&mut app);
+    app.world.entity_mut(floating).insert(GravityScale(0.0));
+    let falling = spawn_body(&mut app);
+
+    run_for_half_a_second(&mut app);
+
+    let floating_velocity = app.world.get::<Velocity>(floating).unwrap().linear;
+    let falling_velocity = app.world.get::<Velocity>(falling).unwrap().linear;
+    assert!(floating_velocity.length() < 0.001);
+    assert!(falling_velocity.y < -1.0);
+}
+
+#[test]
+fn gravity_scale_can_be_changed_at_runtime() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app);
+
+    app.update();
+    app.world.entity_mut(entity).insert(GravityScale(-1.0));
+    run_for_half_a_second(&mut app);
+
+    assert!(app.world.get::<Velocity>(entity).unwrap().linear.y > 1.0);
+}
+
+#[test]
+fn replacing_zone_overrides_world_gravity() {
+    let mut app = test_app();
+    spawn_zone(&mut app, GravityZone::replace(Vec3::X * 10.0));
+    let entity = spawn_body(&mut app);
+
+    run_for_half_a_second(&mut app);
+
+    let velocity = app.world.get::<Velocity>(entity).unwrap().linear;
+    assert!(velocity.x > 1.0);
+    assert!(velocity.y > -1.0);
+}
+
+#[test]
+fn adding_zone_is_added_to_world_gravity() {
+    let mut app = test_app();
+    spawn_zone(&mut app, GravityZone::add(Vec3::X * 10.0));
+    let entity = spawn_body(&mut app);
+
+    run_for_half_a_second(&mut app);
+
+    let velocity = app.world.get::<Velocity>(entity).unwrap().linear;
+    assert!(velocity.x > 1.0);
+    assert!(velocity.y < -1.0);
+}
+
+#[test]
+fn attractor_zone_pulls_bodies_toward_its_origin() {
+    let mut app = test_app();
+    spawn_zone(&mut app, GravityZone::attractor(10.0));
+    let entity = spawn_body_at(&mut app, Vec3::X * 50.0);
+
+    run_for_half_a_second(&mut app);
+
+    let velocity = app.world.get::<Velocity>(entity).unwrap().linear;
+    assert!(velocity.x < -1.0);
+    assert!(velocity.y > -1.0);
+}
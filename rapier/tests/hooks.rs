@@ -0,0 +1,107 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{ActiveCollisionHooks, CollisionShape, Gravity, PhysicsSteps, RigidBody};
+use heron_rapier::{
+    ContactModificationContext, PairFilterContext, PhysicsHooks, PhysicsHooksResource, RapierPlugin,
+};
+
+struct RejectAll;
+
+impl PhysicsHooks for RejectAll {
+    fn filter_contact_pair(&self, _: &PairFilterContext) -> bool {
+        false
+    }
+}
+
+struct ClearContacts;
+
+impl PhysicsHooks for ClearContacts {
+    fn modify_solver_contacts(&self, context: &mut ContactModificationContext<'_, '_>) {
+        context.clear();
+    }
+}
+
+fn test_app(hooks: Option<PhysicsHooksResource>) -> App {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )))
+        .insert_resource(Gravity::from(Vec3::Y * -10.0))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    if let Some(hooks) = hooks {
+        app.insert_resource(hooks);
+    }
+    app
+}
+
+/// Spawn a sphere resting on a floor, and returns the height of the sphere after one second
+fn height_after_one_second(mut app: App, active_hooks: ActiveCollisionHooks) -> f32 {
+    app.world.spawn().insert_bundle((
+        Transform::from_translation(-Vec3::Y),
+        GlobalTransform::from_translation(-Vec3::Y),
+        RigidBody::Static,
+        CollisionShape::Cuboid {
+            half_extends: Vec3::new(10.0, 1.0, 10.0),
+            border_radius: None,
+        },
+        active_hooks,
+    ));
+
+    let sphere = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::from_translation(Vec3::Y),
+            GlobalTransform::from_translation(Vec3::Y),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+
+    for _ in 0..60 {
+        app.update();
+    }
+
+    app.world.get::<Transform>(sphere).unwrap().translation.y
+}
+
+#[test]
+fn sphere_rests_on_the_floor_without_hooks() {
+    let height = height_after_one_second(test_app(None), ActiveCollisionHooks::all());
+    assert!(height > 0.9);
+}
+
+#[test]
+fn rejected_contact_pairs_are_not_solved() {
+    let height = height_after_one_second(
+        test_app(Some(PhysicsHooksResource::new(RejectAll))),
+        ActiveCollisionHooks::filter_contact_pairs(),
+    );
+    assert!(height < 0.0);
+}
+
+#[test]
+fn cleared_solver_contacts_are_not_solved() {
+    let height = height_after_one_second(
+        test_app(Some(PhysicsHooksResource::new(ClearContacts))),
+        ActiveCollisionHooks::modify_solver_contacts(),
+    );
+    assert!(height < 0.0);
+}
+
+#[test]
+fn hooks_are_ignored_if_not_active() {
+    let height = height_after_one_second(
+        test_app(Some(PhysicsHooksResource::new(RejectAll))),
+        ActiveCollisionHooks::default(),
+    );
+    assert!(height > 0.9);
+}
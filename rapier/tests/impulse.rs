@@ -0,0 +1,91 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{AxisAngle, CollisionShape, Impulse, PhysicsSteps, RigidBody, Velocity};
+use heron_rapier::RapierPlugin;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    app
+}
+
+fn spawn_body(app: &mut App) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::default(),
+        ))
+        .id()
+}
+
+#[test]
+fn linear_impulse_is_applied_once() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app);
+
+    app.update();
+
+    app.world
+        .entity_mut(entity)
+        .insert(Impulse::from_linear(Vec3::X * 10.0));
+
+    app.update();
+
+    let velocity = *app.world.get::<Velocity>(entity).unwrap();
+    assert!(velocity.linear.x > 0.0);
+    assert!(app.world.get::<Impulse>(entity).is_none());
+
+    app.update();
+
+    let new_velocity = *app.world.get::<Velocity>(entity).unwrap();
+    assert!((new_velocity.linear.x - velocity.linear.x).abs() < 0.001);
+}
+
+#[test]
+fn angular_impulse_is_applied() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app);
+
+    app.update();
+
+    app.world
+        .entity_mut(entity)
+        .insert(Impulse::from_angular(AxisAngle::new(Vec3::Z, 10.0)));
+
+    app.update();
+
+    let velocity = app.world.get::<Velocity>(entity).unwrap();
+    assert!(velocity.linear.length() < 0.001);
+    assert!(velocity.angular.axis().z > 0.0);
+}
+
+#[test]
+fn impulse_at_point_makes_the_body_spin() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app);
+
+    app.update();
+
+    app.world
+        .entity_mut(entity)
+        .insert(Impulse::at_point(Vec3::X * 10.0, Vec3::Y));
+
+    app.update();
+
+    let velocity = app.world.get::<Velocity>(entity).unwrap();
+    assert!(velocity.linear.x > 0.0);
+    assert!(velocity.angular.axis().z < 0.0);
+}
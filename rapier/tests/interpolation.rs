@@ -0,0 +1,117 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CollisionShape, PhysicsSteps, RigidBody, TransformInterpolation, Velocity};
+use heron_rapier::convert::IntoRapier;
+use heron_rapier::{RapierPlugin, RigidBodyHandle};
+use utils::*;
+
+mod utils;
+
+/// Duration of each frame, shorter than the physics steps of the interpolation tests
+const FRAME_DURATION: Duration = Duration::from_millis(20);
+
+/// The `Time` resource is never updated. Instead, the physics steps advance by exactly
+/// [`FRAME_DURATION`] each frame, so that the frames are deterministic.
+fn test_app(steps: PhysicsSteps) -> App {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .init_resource::<Time>()
+        .insert_resource(steps)
+        .add_plugin(RapierPlugin)
+        .add_system_to_stage(CoreStage::PreUpdate, advance_time.system());
+    app
+}
+
+fn advance_time(mut steps: ResMut<'_, PhysicsSteps>) {
+    steps.tick(FRAME_DURATION);
+}
+
+fn spawn_body(app: &mut App, interpolation: TransformInterpolation) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::from_linear(Vec3::X),
+            interpolation,
+        ))
+        .id()
+}
+
+/// Runs six frames of 20ms, with physics steps every 50ms
+///
+/// The physics steps are performed at the third and fifth frames, and the sixth frame is between
+/// two steps. Returns the `x` position of the body after each of those two steps, and the `x`
+/// translation of its transform at the last frame.
+fn run_until_between_steps(app: &mut App, entity: Entity) -> (f32, f32, f32) {
+    let mut positions = Vec::new();
+    for _ in 0..6 {
+        app.update();
+
+        if app
+            .world
+            .get_resource::<PhysicsSteps>()
+            .unwrap()
+            .is_step_frame()
+        {
+            let handle = app.world.get::<RigidBodyHandle>(entity).unwrap();
+            let bodies = app.world.get_resource::<RigidBodySet>().unwrap();
+            let body = bodies.get(handle.into_rapier()).unwrap();
+            positions.push(body.position().translation.x);
+        }
+    }
+
+    assert!(!app
+        .world
+        .get_resource::<PhysicsSteps>()
+        .unwrap()
+        .is_step_frame());
+    assert_eq!(positions.len(), 2);
+
+    let rendered = app.world.get::<Transform>(entity).unwrap().translation.x;
+    (positions[0], positions[1], rendered)
+}
+
+#[test]
+fn transform_is_not_interpolated_when_stepping_every_frame() {
+    let mut app = test_app(PhysicsSteps::every_frame(Duration::from_secs(1)));
+    let entity = spawn_body(&mut app, TransformInterpolation::Interpolate);
+
+    app.update();
+    app.update();
+    app.update();
+
+    let translation = app.world.get::<Transform>(entity).unwrap().translation;
+    assert!((translation.x - 3.0).abs() < 0.001);
+}
+
+#[test]
+fn transform_is_interpolated_between_the_two_last_steps() {
+    let mut app = test_app(PhysicsSteps::from_delta_time(Duration::from_millis(50)));
+    let entity = spawn_body(&mut app, TransformInterpolation::Interpolate);
+
+    let (previous, current, rendered) = run_until_between_steps(&mut app, entity);
+
+    assert!(previous < current);
+    assert!(rendered > previous);
+    assert!(rendered < current);
+}
+
+#[test]
+fn transform_is_extrapolated_beyond_the_last_step() {
+    let mut app = test_app(PhysicsSteps::from_delta_time(Duration::from_millis(50)));
+    let entity = spawn_body(&mut app, TransformInterpolation::Extrapolate);
+
+    let (previous, current, rendered) = run_until_between_steps(&mut app, entity);
+
+    assert!(previous < current);
+    assert!(rendered > current);
+    assert!(rendered < current + (current - previous));
+}
@@ -0,0 +1,237 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::app::Events;
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{
+    CollisionShape, CollisionShapeError, CollisionShapeErrorEvent, CompoundShapeChild,
+    InvalidCollisionShape, PhysicsSteps, RigidBody, Velocity,
+};
+use heron_rapier::{ColliderHandle, RapierPlugin};
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    app
+}
+
+fn spawn_body(app: &mut App, shape: CollisionShape) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            shape,
+        ))
+        .id()
+}
+
+fn invalid_convex_hull() -> CollisionShape {
+    CollisionShape::ConvexHull {
+        points: vec![Vec3::ZERO],
+        border_radius: None,
+    }
+}
+
+fn error_of(shape: CollisionShape) -> Option<CollisionShapeError> {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, shape);
+    app.update();
+    assert_eq!(
+        app.world.get::<ColliderHandle>(entity).is_none(),
+        app.world.get::<InvalidCollisionShape>(entity).is_some()
+    );
+    app.world
+        .get::<InvalidCollisionShape>(entity)
+        .map(|invalid| invalid.error.clone())
+}
+
+fn triangle() -> Vec<Vec3> {
+    vec![Vec3::ZERO, Vec3::X, Vec3::Y]
+}
+
+#[test]
+fn invalid_shape_is_flagged() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, invalid_convex_hull());
+
+    app.update();
+
+    assert!(app.world.get::<ColliderHandle>(entity).is_none());
+    assert_eq!(
+        app.world.get::<InvalidCollisionShape>(entity),
+        Some(&InvalidCollisionShape {
+            error: CollisionShapeError::InvalidConvexHull
+        })
+    );
+}
+
+#[test]
+fn error_event_is_fired_once() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, invalid_convex_hull());
+
+    app.update();
+    app.update();
+
+    let events = app
+        .world
+        .get_resource::<Events<CollisionShapeErrorEvent>>()
+        .unwrap();
+    let events: Vec<_> = events.get_reader().iter(events).cloned().collect();
+
+    assert_eq!(
+        events,
+        vec![CollisionShapeErrorEvent {
+            entity,
+            error: CollisionShapeError::InvalidConvexHull,
+        }]
+    );
+}
+
+#[test]
+fn other_bodies_keep_being_simulated() {
+    let mut app = test_app();
+    spawn_body(&mut app, invalid_convex_hull());
+    let valid = spawn_body(&mut app, CollisionShape::Sphere { radius: 1.0 });
+    app.world
+        .entity_mut(valid)
+        .insert(Velocity::from_linear(Vec3::X));
+
+    app.update();
+    app.update();
+
+    assert!(app.world.get::<ColliderHandle>(valid).is_some());
+    assert!(app.world.get::<Transform>(valid).unwrap().translation.x > 0.0);
+}
+
+#[test]
+fn shape_is_created_once_fixed() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, invalid_convex_hull());
+
+    app.update();
+    app.world
+        .entity_mut(entity)
+        .insert(CollisionShape::Sphere { radius: 1.0 });
+    app.update();
+
+    assert!(app.world.get::<InvalidCollisionShape>(entity).is_none());
+    assert!(app.world.get::<ColliderHandle>(entity).is_some());
+}
+
+#[test]
+fn shapes_without_elements_are_invalid() {
+    let shapes = vec![
+        CollisionShape::Polyline {
+            vertices: vec![Vec3::ZERO],
+            indices: None,
+        },
+        CollisionShape::Polyline {
+            vertices: triangle(),
+            indices: Some(Vec::new()),
+        },
+        CollisionShape::TriMesh {
+            vertices: triangle(),
+            indices: Vec::new(),
+        },
+        CollisionShape::Compound { shapes: Vec::new() },
+    ];
+
+    for shape in shapes {
+        assert_eq!(error_of(shape), Some(CollisionShapeError::EmptyShape));
+    }
+}
+
+#[test]
+fn indices_out_of_bounds_are_invalid() {
+    let shapes = vec![
+        CollisionShape::Polyline {
+            vertices: triangle(),
+            indices: Some(vec![[0, 1], [1, 3]]),
+        },
+        CollisionShape::TriMesh {
+            vertices: triangle(),
+            indices: vec![[0, 1, 2], [0, 2, 3]],
+        },
+    ];
+
+    for shape in shapes {
+        assert_eq!(error_of(shape), Some(CollisionShapeError::IndexOutOfBounds));
+    }
+}
+
+#[test]
+fn nested_composite_shapes_are_invalid() {
+    let shape = CollisionShape::Compound {
+        shapes: vec![
+            CompoundShapeChild::new(CollisionShape::Sphere { radius: 1.0 }),
+            CompoundShapeChild::new(CollisionShape::Compound {
+                shapes: vec![CompoundShapeChild::new(CollisionShape::Sphere {
+                    radius: 1.0,
+                })],
+            }),
+        ],
+    };
+
+    assert_eq!(error_of(shape), Some(CollisionShapeError::NestedCompound));
+}
+
+#[test]
+fn non_finite_vertices_are_invalid() {
+    let shapes = vec![
+        CollisionShape::Segment {
+            a: Vec3::ZERO,
+            b: Vec3::new(f32::NAN, 0.0, 0.0),
+        },
+        CollisionShape::Triangle {
+            a: Vec3::ZERO,
+            b: Vec3::X,
+            c: Vec3::new(0.0, f32::INFINITY, 0.0),
+        },
+        CollisionShape::Polyline {
+            vertices: vec![Vec3::ZERO, Vec3::new(f32::NEG_INFINITY, 0.0, 0.0)],
+            indices: None,
+        },
+        CollisionShape::TriMesh {
+            vertices: vec![Vec3::ZERO, Vec3::X, Vec3::new(0.0, f32::NAN, 0.0)],
+            indices: vec![[0, 1, 2]],
+        },
+    ];
+
+    for shape in shapes {
+        assert_eq!(error_of(shape), Some(CollisionShapeError::NonFiniteVertex));
+    }
+}
+
+#[test]
+fn valid_meshes_are_built() {
+    let shapes = vec![
+        CollisionShape::Polyline {
+            vertices: triangle(),
+            indices: Some(vec![[0, 1], [1, 2]]),
+        },
+        CollisionShape::TriMesh {
+            vertices: triangle(),
+            indices: vec![[0, 1, 2]],
+        },
+        CollisionShape::Compound {
+            shapes: vec![CompoundShapeChild::new(CollisionShape::Segment {
+                a: Vec3::ZERO,
+                b: Vec3::X,
+            })],
+        },
+    ];
+
+    for shape in shapes {
+        assert_eq!(error_of(shape), None);
+    }
+}
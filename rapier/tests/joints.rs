@@ -0,0 +1,170 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CollisionShape, Joint, PhysicsSteps, RigidBody};
+use heron_rapier::convert::IntoRapier;
+use heron_rapier::{JointHandle, RapierPlugin, RigidBodyHandle};
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    app
+}
+
+fn spawn_bodies(app: &mut App) -> (Entity, Entity) {
+    let body1 = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::default(),
+            RigidBody::Static,
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+
+    let body2 = app
+        .world
+        .spawn()
+        .insert_bundle((
+            GlobalTransform::from_translation(Vec3::X * 5.0),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+
+    (body1, body2)
+}
+
+#[test]
+fn joint_is_created_between_the_bodies() {
+    let mut app = test_app();
+    let (body1, body2) = spawn_bodies(&mut app);
+
+    let entity = app
+        .world
+        .spawn()
+        .insert(Joint::ball(body1, body2).with_anchors(Vec3::X * 5.0, Vec3::ZERO))
+        .id();
+
+    app.update();
+
+    let handle = app
+        .world
+        .get::<JointHandle>(entity)
+        .expect("No joint handle inserted");
+
+    let joints = app.world.get_resource::<JointSet>().unwrap();
+    let joint = joints
+        .get(handle.into_rapier())
+        .expect("No joint referenced by the handle");
+
+    assert_eq!(
+        joint.body1,
+        app.world
+            .get::<RigidBodyHandle>(body1)
+            .unwrap()
+            .into_rapier()
+    );
+    assert_eq!(
+        joint.body2,
+        app.world
+            .get::<RigidBodyHandle>(body2)
+            .unwrap()
+            .into_rapier()
+    );
+}
+
+#[test]
+fn joint_is_not_created_without_bodies() {
+    let mut app = test_app();
+
+    let body1 = app.world.spawn().id();
+    let body2 = app.world.spawn().id();
+    let entity = app.world.spawn().insert(Joint::fixed(body1, body2)).id();
+
+    app.update();
+
+    assert!(app.world.get::<JointHandle>(entity).is_none());
+    assert_eq!(app.world.get_resource::<JointSet>().unwrap().len(), 0);
+}
+
+#[test]
+fn joint_is_removed_with_the_component() {
+    let mut app = test_app();
+    let (body1, body2) = spawn_bodies(&mut app);
+
+    let entity = app.world.spawn().insert(Joint::fixed(body1, body2)).id();
+
+    app.update();
+    app.world.entity_mut(entity).remove::<Joint>();
+    app.update();
+
+    assert!(app.world.get::<JointHandle>(entity).is_none());
+    assert_eq!(app.world.get_resource::<JointSet>().unwrap().len(), 0);
+}
+
+#[test]
+fn joint_is_removed_with_a_body() {
+    let mut app = test_app();
+    let (body1, body2) = spawn_bodies(&mut app);
+
+    let entity = app.world.spawn().insert(Joint::fixed(body1, body2)).id();
+
+    app.update();
+    app.world.despawn(body2);
+    app.update();
+
+    assert!(app.world.get::<JointHandle>(entity).is_none());
+    assert_eq!(app.world.get_resource::<JointSet>().unwrap().len(), 0);
+}
+
+#[test]
+fn joint_is_recreated_when_a_body_is_recreated() {
+    let mut app = test_app();
+    let (body1, body2) = spawn_bodies(&mut app);
+
+    let entity = app.world.spawn().insert(Joint::fixed(body1, body2)).id();
+
+    app.update();
+    app.world
+        .entity_mut(body2)
+        .insert(RigidBody::KinematicPositionBased);
+    app.update();
+
+    assert!(app.world.get::<JointHandle>(entity).is_some());
+    assert_eq!(app.world.get_resource::<JointSet>().unwrap().len(), 1);
+}
+
+#[test]
+fn joint_with_zero_axis_does_not_break_the_simulation() {
+    let mut app = test_app();
+    let (body1, body2) = spawn_bodies(&mut app);
+
+    app.world
+        .spawn()
+        .insert(Joint::prismatic(body1, body2, Vec3::ZERO));
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let handle = app.world.get::<RigidBodyHandle>(body2).unwrap();
+    let bodies = app.world.get_resource::<RigidBodySet>().unwrap();
+    let position = bodies.get(handle.into_rapier()).unwrap().position();
+    assert!(
+        position.translation.vector.iter().all(|x| x.is_finite()),
+        "{:?}",
+        position
+    );
+}
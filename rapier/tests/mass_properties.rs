@@ -0,0 +1,119 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{
+    CollisionShape, ComputedMassProperties, MassProperties, PhysicMaterial, PhysicsSteps, RigidBody,
+};
+use heron_rapier::{RapierPlugin, RigidBodyHandle};
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    app
+}
+
+fn spawn_body(app: &mut App) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            ComputedMassProperties::default(),
+        ))
+        .id()
+}
+
+#[test]
+fn computed_mass_reflects_density() {
+    let mut app = test_app();
+    let light = spawn_body(&mut app);
+    let heavy = spawn_body(&mut app);
+    app.world.entity_mut(heavy).insert(PhysicMaterial {
+        density: 2.0,
+        ..PhysicMaterial::default()
+    });
+
+    app.update();
+
+    let light_mass = app
+        .world
+        .get::<ComputedMassProperties>(light)
+        .unwrap()
+        .mass();
+    let heavy_mass = app
+        .world
+        .get::<ComputedMassProperties>(heavy)
+        .unwrap()
+        .mass();
+
+    assert!(light_mass > 0.0);
+    assert!((heavy_mass - 2.0 * light_mass).abs() < 0.001);
+}
+
+#[test]
+fn explicit_mass_overrides_density() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app);
+    app.world.entity_mut(entity).insert(
+        MassProperties::from_mass(10.0).with_local_center_of_mass(Vec3::new(0.5, 0.0, 0.0)),
+    );
+
+    app.update();
+
+    let computed = *app.world.get::<ComputedMassProperties>(entity).unwrap();
+    assert!((computed.mass() - 10.0).abs() < 0.001);
+    assert!(
+        computed
+            .local_center_of_mass()
+            .distance(Vec3::new(0.5, 0.0, 0.0))
+            < 0.001
+    );
+}
+
+#[test]
+fn changing_explicit_mass_updates_the_body() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app);
+    app.world
+        .entity_mut(entity)
+        .insert(MassProperties::from_mass(10.0));
+
+    app.update();
+    let handle = *app.world.get::<RigidBodyHandle>(entity).unwrap();
+
+    app.world.get_mut::<MassProperties>(entity).unwrap().mass = 3.0;
+
+    app.update();
+
+    let computed = app.world.get::<ComputedMassProperties>(entity).unwrap();
+    assert!((computed.mass() - 3.0).abs() < 0.001);
+    assert_eq!(app.world.get::<RigidBodyHandle>(entity), Some(&handle));
+}
+
+#[test]
+fn inserting_explicit_mass_ignores_density() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app);
+
+    app.update();
+
+    app.world
+        .entity_mut(entity)
+        .insert(MassProperties::from_mass(10.0));
+
+    app.update();
+    app.update();
+
+    let computed = app.world.get::<ComputedMassProperties>(entity).unwrap();
+    assert!((computed.mass() - 10.0).abs() < 0.001);
+}
@@ -0,0 +1,118 @@
+#![cfg(all(any(dim2, dim3), feature = "collision-from-mesh"))]
+
+use std::time::Duration;
+
+use bevy::asset::{AssetPlugin, HandleId};
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::render::mesh::{shape, Indices};
+
+use heron_core::{CollisionShape, MeshCollisionShape, PhysicsSteps, RigidBody};
+use heron_rapier::convert::IntoRapier;
+use heron_rapier::{ColliderHandle, RapierPlugin};
+
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(AssetPlugin)
+        .add_asset::<Mesh>()
+        .add_plugin(RapierPlugin);
+    app
+}
+
+fn spawn_static_body(app: &mut App, mesh: Handle<Mesh>) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Static,
+            MeshCollisionShape::trimesh(mesh),
+        ))
+        .id()
+}
+
+fn triangle_count(app: &App, entity: Entity) -> usize {
+    let handle = app.world.get::<ColliderHandle>(entity).unwrap();
+    let colliders = app.world.get_resource::<ColliderSet>().unwrap();
+    colliders
+        .get(handle.into_rapier())
+        .unwrap()
+        .shape()
+        .as_trimesh()
+        .unwrap()
+        .num_triangles()
+}
+
+#[test]
+fn shape_is_created_from_a_loaded_mesh() {
+    let mut app = test_app();
+    let mesh = app
+        .world
+        .get_resource_mut::<Assets<Mesh>>()
+        .unwrap()
+        .add(Mesh::from(shape::Cube::new(1.0)));
+    let entity = spawn_static_body(&mut app, mesh);
+
+    app.update();
+
+    assert!(matches!(
+        app.world.get::<CollisionShape>(entity),
+        Some(CollisionShape::TriMesh { .. })
+    ));
+    assert_eq!(triangle_count(&app, entity), 12);
+}
+
+#[test]
+fn shape_is_created_once_the_mesh_is_loaded() {
+    let mut app = test_app();
+    let mesh = app
+        .world
+        .get_resource::<Assets<Mesh>>()
+        .unwrap()
+        .get_handle(HandleId::random::<Mesh>());
+    let entity = spawn_static_body(&mut app, mesh.clone());
+
+    app.update();
+    assert!(app.world.get::<CollisionShape>(entity).is_none());
+
+    app.world
+        .get_resource_mut::<Assets<Mesh>>()
+        .unwrap()
+        .set_untracked(mesh, Mesh::from(shape::Cube::new(1.0)));
+    app.update();
+    app.update();
+
+    assert_eq!(triangle_count(&app, entity), 12);
+}
+
+#[test]
+fn shape_is_rebuilt_when_the_mesh_is_modified() {
+    let mut app = test_app();
+    let mesh = app
+        .world
+        .get_resource_mut::<Assets<Mesh>>()
+        .unwrap()
+        .add(Mesh::from(shape::Cube::new(1.0)));
+    let entity = spawn_static_body(&mut app, mesh.clone());
+
+    app.update();
+
+    app.world
+        .get_resource_mut::<Assets<Mesh>>()
+        .unwrap()
+        .get_mut(&mesh)
+        .unwrap()
+        .set_indices(Some(Indices::U32(vec![0, 1, 2])));
+    app.update();
+    app.update();
+
+    assert_eq!(triangle_count(&app, entity), 1);
+}
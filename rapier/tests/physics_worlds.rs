@@ -0,0 +1,125 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CollisionShape, Gravity, PhysicsSteps, PhysicsWorldId, RigidBody, Velocity};
+use heron_rapier::{PhysicsWorlds, RapierPlugin, RigidBodyHandle};
+
+const OTHER_WORLD: PhysicsWorldId = PhysicsWorldId(1);
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+
+    app.world
+        .get_resource_mut::<PhysicsWorlds>()
+        .unwrap()
+        .add(OTHER_WORLD)
+        .steps = PhysicsSteps::every_frame(Duration::from_secs_f32(1.0 / 60.0));
+
+    app
+}
+
+fn spawn_body(app: &mut App, world: Option<PhysicsWorldId>) -> Entity {
+    let mut entity = app.world.spawn();
+    entity.insert_bundle((
+        Transform::default(),
+        GlobalTransform::default(),
+        RigidBody::Dynamic,
+        CollisionShape::Sphere { radius: 1.0 },
+        Velocity::default(),
+    ));
+    if let Some(world) = world {
+        entity.insert(world);
+    }
+    entity.id()
+}
+
+#[test]
+fn bodies_use_the_gravity_of_their_world() {
+    let mut app = test_app();
+    app.insert_resource(Gravity::from(Vec3::Y * -10.0));
+    app.world
+        .get_resource_mut::<PhysicsWorlds>()
+        .unwrap()
+        .settings_mut(OTHER_WORLD)
+        .unwrap()
+        .gravity = Gravity::from(Vec3::Y * 10.0);
+
+    let falling = spawn_body(&mut app, None);
+    let rising = spawn_body(&mut app, Some(OTHER_WORLD));
+
+    for _ in 0..30 {
+        app.update();
+    }
+
+    assert!(app.world.get::<Velocity>(falling).unwrap().linear.y < -1.0);
+    assert!(app.world.get::<Velocity>(rising).unwrap().linear.y > 1.0);
+    assert!(app.world.get::<Transform>(falling).unwrap().translation.y < 0.0);
+    assert!(app.world.get::<Transform>(rising).unwrap().translation.y > 0.0);
+}
+
+#[test]
+fn bodies_of_different_worlds_do_not_collide() {
+    let mut app = test_app();
+    let body1 = spawn_body(&mut app, None);
+    let body2 = spawn_body(&mut app, Some(OTHER_WORLD));
+
+    for _ in 0..10 {
+        app.update();
+    }
+
+    assert_eq!(
+        app.world.get::<Transform>(body1).unwrap().translation,
+        Vec3::ZERO
+    );
+    assert_eq!(
+        app.world.get::<Transform>(body2).unwrap().translation,
+        Vec3::ZERO
+    );
+}
+
+#[test]
+fn bodies_of_the_same_world_collide() {
+    let mut app = test_app();
+    let body1 = spawn_body(&mut app, Some(OTHER_WORLD));
+    let body2 = spawn_body(&mut app, Some(OTHER_WORLD));
+    app.world.get_mut::<Transform>(body2).unwrap().translation.x = 0.5;
+
+    for _ in 0..10 {
+        app.update();
+    }
+
+    let x1 = app.world.get::<Transform>(body1).unwrap().translation.x;
+    let x2 = app.world.get::<Transform>(body2).unwrap().translation.x;
+    assert!(x2 - x1 > 0.5);
+}
+
+#[test]
+fn body_of_unregistered_world_is_not_created() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, Some(PhysicsWorldId(2)));
+
+    app.update();
+
+    assert!(app.world.get::<RigidBodyHandle>(entity).is_none());
+}
+
+#[test]
+fn body_of_registered_world_is_created() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, Some(OTHER_WORLD));
+
+    app.update();
+
+    assert!(app.world.get::<RigidBodyHandle>(entity).is_some());
+}
@@ -0,0 +1,138 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CollisionShape, PhysicsSteps, RigidBody};
+use heron_rapier::convert::{IntoBevy, IntoRapier};
+use heron_rapier::{ColliderHandle, RapierPlugin};
+
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    app
+}
+
+fn spawn_body(app: &mut App, shape: CollisionShape, scale: Vec3) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::from_scale(scale),
+            GlobalTransform::default(),
+            RigidBody::Static,
+            shape,
+        ))
+        .id()
+}
+
+fn sphere_radius(app: &App, entity: Entity) -> f32 {
+    let handle = app.world.get::<ColliderHandle>(entity).unwrap();
+    let colliders = app.world.get_resource::<ColliderSet>().unwrap();
+    colliders
+        .get(handle.into_rapier())
+        .unwrap()
+        .shape()
+        .as_ball()
+        .unwrap()
+        .radius
+}
+
+#[test]
+fn sphere_is_scaled() {
+    let mut app = test_app();
+    let entity = spawn_body(
+        &mut app,
+        CollisionShape::Sphere { radius: 1.0 },
+        Vec3::splat(2.0),
+    );
+
+    app.update();
+
+    assert_eq!(sphere_radius(&app, entity), 2.0);
+}
+
+#[test]
+fn cuboid_follows_non_uniform_scale() {
+    let mut app = test_app();
+    let entity = spawn_body(
+        &mut app,
+        CollisionShape::Cuboid {
+            half_extends: Vec3::ONE,
+            border_radius: None,
+        },
+        Vec3::new(2.0, 3.0, 4.0),
+    );
+
+    app.update();
+
+    let handle = app.world.get::<ColliderHandle>(entity).unwrap();
+    let colliders = app.world.get_resource::<ColliderSet>().unwrap();
+    let cuboid = colliders
+        .get(handle.into_rapier())
+        .unwrap()
+        .shape()
+        .as_cuboid()
+        .unwrap();
+
+    assert_eq!(cuboid.half_extents.x, 2.0);
+    assert_eq!(cuboid.half_extents.y, 3.0);
+    #[cfg(dim3)]
+    assert_eq!(cuboid.half_extents.z, 4.0);
+}
+
+#[test]
+fn collider_is_rebuilt_when_scale_changes() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, CollisionShape::Sphere { radius: 1.0 }, Vec3::ONE);
+
+    app.update();
+    assert_eq!(sphere_radius(&app, entity), 1.0);
+
+    app.world.get_mut::<Transform>(entity).unwrap().scale = Vec3::splat(3.0);
+    app.update();
+
+    assert_eq!(sphere_radius(&app, entity), 3.0);
+    assert_eq!(app.world.get_resource::<ColliderSet>().unwrap().len(), 1);
+}
+
+#[test]
+fn child_shape_is_positioned_in_the_scaled_space_of_its_parent() {
+    let mut app = test_app();
+    let child = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Transform::from_translation(Vec3::X),
+            GlobalTransform::default(),
+            CollisionShape::Sphere { radius: 1.0 },
+        ))
+        .id();
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::from_scale(Vec3::splat(2.0)),
+            GlobalTransform::default(),
+            RigidBody::Static,
+        ))
+        .push_children(&[child]);
+
+    app.update();
+
+    let handle = app.world.get::<ColliderHandle>(child).unwrap();
+    let colliders = app.world.get_resource::<ColliderSet>().unwrap();
+    let collider = colliders.get(handle.into_rapier()).unwrap();
+    let (translation, _) = collider.position().into_bevy();
+
+    assert_eq!(translation.x, 2.0);
+    assert_eq!(collider.shape().as_ball().unwrap().radius, 2.0);
+}
@@ -0,0 +1,164 @@
+#![cfg(any(dim2, dim3))]
+
+use std::time::Duration;
+
+use bevy::app::{Events, ManualEventReader};
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CollisionShape, PhysicsSteps, RigidBody, SleepEvent, Sleeping};
+use heron_rapier::RapierPlugin;
+
+/// Rapier smooths the energy of the bodies, which takes about 140 steps to fall below the threshold
+const STEPS_TO_FALL_ASLEEP: usize = 150;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    app
+}
+
+fn spawn_body(app: &mut App, sleeping: Sleeping) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            sleeping,
+        ))
+        .id()
+}
+
+fn collect_events(app: &App, reader: &mut ManualEventReader<SleepEvent>) -> Vec<SleepEvent> {
+    let events = app.world.get_resource::<Events<SleepEvent>>().unwrap();
+    reader.iter(events).copied().collect()
+}
+
+#[test]
+fn resting_body_falls_asleep() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, Sleeping::default());
+    let mut reader = app
+        .world
+        .get_resource::<Events<SleepEvent>>()
+        .unwrap()
+        .get_reader();
+
+    let mut events = Vec::new();
+    for _ in 0..STEPS_TO_FALL_ASLEEP {
+        app.update();
+        events.append(&mut collect_events(&app, &mut reader));
+    }
+
+    assert!(app.world.get::<Sleeping>(entity).unwrap().sleeping);
+    assert_eq!(events, vec![SleepEvent::FellAsleep(entity)]);
+}
+
+#[test]
+fn body_does_not_sleep_when_disabled() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, Sleeping::disabled());
+
+    for _ in 0..STEPS_TO_FALL_ASLEEP {
+        app.update();
+    }
+
+    assert!(!app.world.get::<Sleeping>(entity).unwrap().sleeping);
+}
+
+#[test]
+fn body_cannot_be_forced_to_sleep_when_disabled() {
+    let mut app = test_app();
+    let spawned_asleep = spawn_body(&mut app, Sleeping::asleep().with_threshold(-1.0));
+    let put_to_sleep = spawn_body(&mut app, Sleeping::disabled());
+
+    app.update();
+    app.world
+        .get_mut::<Sleeping>(put_to_sleep)
+        .unwrap()
+        .sleeping = true;
+    app.update();
+    app.update();
+
+    assert!(!app.world.get::<Sleeping>(spawned_asleep).unwrap().sleeping);
+    assert!(!app.world.get::<Sleeping>(put_to_sleep).unwrap().sleeping);
+}
+
+#[test]
+fn sleeping_body_can_be_woken_up() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, Sleeping::asleep());
+    let mut reader = app
+        .world
+        .get_resource::<Events<SleepEvent>>()
+        .unwrap()
+        .get_reader();
+
+    let mut events = Vec::new();
+
+    app.update();
+    events.append(&mut collect_events(&app, &mut reader));
+    app.update();
+    events.append(&mut collect_events(&app, &mut reader));
+    assert!(app.world.get::<Sleeping>(entity).unwrap().sleeping);
+
+    app.world.get_mut::<Sleeping>(entity).unwrap().sleeping = false;
+    app.update();
+    events.append(&mut collect_events(&app, &mut reader));
+    app.update();
+    events.append(&mut collect_events(&app, &mut reader));
+
+    assert!(!app.world.get::<Sleeping>(entity).unwrap().sleeping);
+    assert_eq!(
+        events,
+        vec![SleepEvent::FellAsleep(entity), SleepEvent::WokeUp(entity)]
+    );
+}
+
+#[test]
+fn events_are_fired_for_bodies_without_sleeping_component() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, Sleeping::default());
+    app.world.entity_mut(entity).remove::<Sleeping>();
+    let mut reader = app
+        .world
+        .get_resource::<Events<SleepEvent>>()
+        .unwrap()
+        .get_reader();
+
+    let mut events = Vec::new();
+    for _ in 0..STEPS_TO_FALL_ASLEEP {
+        app.update();
+        events.append(&mut collect_events(&app, &mut reader));
+    }
+
+    assert_eq!(events, vec![SleepEvent::FellAsleep(entity)]);
+}
+
+#[test]
+fn despawned_body_does_not_fire_events() {
+    let mut app = test_app();
+    let entity = spawn_body(&mut app, Sleeping::disabled());
+    let mut reader = app
+        .world
+        .get_resource::<Events<SleepEvent>>()
+        .unwrap()
+        .get_reader();
+
+    app.update();
+    app.world.despawn(entity);
+
+    let mut events = Vec::new();
+    for _ in 0..3 {
+        app.update();
+        events.append(&mut collect_events(&app, &mut reader));
+    }
+
+    assert!(events.is_empty());
+}
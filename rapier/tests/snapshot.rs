@@ -0,0 +1,130 @@
+#![cfg(all(any(dim2, dim3), snapshot))]
+
+use std::time::Duration;
+
+use bevy::core::CorePlugin;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+
+use heron_core::{CollisionShape, PhysicsSteps, PhysicsWorldId, RigidBody, Velocity};
+use heron_rapier::convert::IntoRapier;
+use heron_rapier::{ColliderHandle, PhysicsSnapshot, PhysicsWorlds, RapierPlugin, RigidBodyHandle};
+use utils::*;
+
+mod utils;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<TypeRegistryArc>()
+        .insert_resource(PhysicsSteps::every_frame(Duration::from_secs(1)))
+        .add_plugin(CorePlugin)
+        .add_plugin(RapierPlugin);
+    app
+}
+
+fn spawn_ball(app: &mut App) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            Transform::default(),
+            GlobalTransform::default(),
+            RigidBody::Dynamic,
+            CollisionShape::Sphere { radius: 1.0 },
+            Velocity::from_linear(Vec3::X),
+        ))
+        .id()
+}
+
+#[test]
+fn restore_resumes_the_simulation_from_the_snapshot() {
+    let mut app = test_app();
+    let entity = spawn_ball(&mut app);
+
+    app.update();
+    let snapshot = PhysicsSnapshot::capture(&app.world);
+    let translation_at_capture = app.world.get::<Transform>(entity).unwrap().translation;
+
+    app.update();
+    app.update();
+
+    snapshot.clone().restore(&mut app.world);
+    app.update();
+
+    let translation = app.world.get::<Transform>(entity).unwrap().translation;
+    assert!((translation.x - (translation_at_capture.x + 1.0)).abs() < 0.001);
+    assert!(app.world.get::<RigidBodyHandle>(entity).is_some());
+}
+
+#[test]
+fn restore_a_deserialized_snapshot() {
+    let mut app = test_app();
+    let entity = spawn_ball(&mut app);
+
+    app.update();
+    let serialized = ron::to_string(&PhysicsSnapshot::capture(&app.world)).unwrap();
+    let translation_at_capture = app.world.get::<Transform>(entity).unwrap().translation;
+
+    app.update();
+    app.update();
+
+    let snapshot: PhysicsSnapshot = ron::from_str(&serialized).unwrap();
+    snapshot.restore(&mut app.world);
+    app.update();
+
+    let translation = app.world.get::<Transform>(entity).unwrap().translation;
+    assert!((translation.x - (translation_at_capture.x + 1.0)).abs() < 0.001);
+}
+
+#[test]
+fn restore_recreates_the_bodies_absent_from_the_snapshot() {
+    let mut app = test_app();
+    app.update();
+    let snapshot = PhysicsSnapshot::capture(&app.world);
+
+    let entity = spawn_ball(&mut app);
+    app.update();
+
+    snapshot.restore(&mut app.world);
+    assert!(app.world.get::<RigidBodyHandle>(entity).is_none());
+    assert!(app.world.get::<ColliderHandle>(entity).is_none());
+
+    app.update();
+
+    let handle = app.world.get::<RigidBodyHandle>(entity).unwrap();
+    let bodies = app.world.get_resource::<RigidBodySet>().unwrap();
+    assert_eq!(bodies.len(), 1);
+    assert!(bodies.get(handle.into_rapier()).is_some());
+    assert!(app.world.get::<ColliderHandle>(entity).is_some());
+}
+
+#[test]
+fn restore_keeps_the_bodies_of_the_other_worlds() {
+    let mut app = test_app();
+    let other_world = PhysicsWorldId(1);
+    app.world
+        .get_resource_mut::<PhysicsWorlds>()
+        .unwrap()
+        .add(other_world)
+        .steps = PhysicsSteps::every_frame(Duration::from_secs(1));
+
+    let entity = spawn_ball(&mut app);
+    app.world.entity_mut(entity).insert(other_world);
+    app.update();
+
+    let body_handle = *app.world.get::<RigidBodyHandle>(entity).unwrap();
+    let collider_handle = *app.world.get::<ColliderHandle>(entity).unwrap();
+
+    PhysicsSnapshot::capture(&app.world).restore(&mut app.world);
+    app.update();
+
+    // The body is neither removed nor created a second time in its world
+    assert_eq!(
+        *app.world.get::<RigidBodyHandle>(entity).unwrap(),
+        body_handle
+    );
+    assert_eq!(
+        *app.world.get::<ColliderHandle>(entity).unwrap(),
+        collider_handle
+    );
+    assert_eq!(app.world.get_resource::<RigidBodySet>().unwrap().len(), 0);
+}
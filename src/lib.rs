@@ -31,7 +31,10 @@
 //!
 //! * `3d` Enable simulation on the 3 axes `x`, `y`, and `z`. Incompatible with the feature `2d`.
 //! * `2d` Enable simulation only on the first 2 axes `x` and `y`. Incompatible with the feature `3d`, therefore require to disable the default features.
-//! * `debug-2d` Render 2d collision shapes. Works only in 2d, support for 3d may be added later.
+//! * `debug-2d` Render 2d collision shapes.
+//! * `debug-3d` Render 3d collision shapes (as wireframes).
+//! * `serde-2d`/`serde-3d` Enable serializable snapshots of the physics world (see `rapier_plugin::PhysicsSnapshot`).
+//! * `collision-from-mesh` Enable the generation of collision shapes from bevy meshes (see `MeshCollisionShape`).
 //!
 //! ## Install the plugin
 //!
@@ -117,12 +120,16 @@
 //! * How to define the world's [`PhysicsTime`]
 //! * How to define the [`PhysicMaterial`]
 //! * How to listen to [`CollisionEvent`]
-//! * How to define [`RotationConstraints`]
+//! * How to define [`RotationConstraints`] and [`TranslationConstraints`]
+//! * How to define the [`MassProperties`] of a rigid body
+//! * How to connect rigid bodies with a [`Joint`]
 //! * How to define [`CustomCollisionShape`] for [`heron_rapier`]
 
 use bevy::app::{App, Plugin};
 
 pub use heron_core::*;
+#[cfg(debug)]
+pub use heron_debug::DebugOverlays;
 pub use heron_macros::*;
 use heron_rapier::RapierPlugin;
 
@@ -139,10 +146,18 @@ pub mod prelude {
 
     #[allow(deprecated)]
     pub use crate::{
-        stage, Acceleration, AxisAngle, CollisionEvent, CollisionLayers, CollisionShape, Damping,
-        Gravity, PhysicMaterial, PhysicsLayer, PhysicsPlugin, PhysicsSystem, PhysicsTime,
-        RigidBody, RotationConstraints, Velocity,
+        stage, Acceleration, ActiveCollisionEvents, ActiveCollisionHooks, AxisAngle,
+        CharacterController, CollisionEvent, CollisionEventQueue, CollisionLayers, CollisionShape,
+        CollisionShapeErrorEvent, Collisions, CombineRule, CompoundShapeChild,
+        ComputedMassProperties, ContinuousCollisionDetection, Damping, EntityCollisionEvent,
+        Gravity, GravityScale, GravityZone, Impulse, InvalidCollisionShape, Joint, JointKind,
+        MassProperties, PhysicMaterial, PhysicsLayer, PhysicsPlugin, PhysicsSystem, PhysicsTime,
+        PhysicsWorldId, RigidBody, RotationConstraints, SleepEvent, Sleeping,
+        TransformInterpolation, TranslationConstraints, Velocity,
     };
+
+    #[cfg(feature = "collision-from-mesh")]
+    pub use crate::{MeshCollisionShape, MeshShapeKind};
 }
 
 /// Plugin to install to enable collision detection and physics behavior.
@@ -153,6 +168,18 @@ pub struct PhysicsPlugin {
     debug: heron_debug::DebugPlugin,
 }
 
+impl PhysicsPlugin {
+    /// Draw the given debug overlays on top of the collision shapes (requires the `debug-2d` or
+    /// `debug-3d` feature)
+    ///
+    /// They can also be toggled at runtime with the [`DebugOverlays`] resource.
+    #[cfg(debug)]
+    pub fn with_debug_overlays(mut self, overlays: DebugOverlays) -> Self {
+        self.debug = self.debug.with_overlays(overlays);
+        self
+    }
+}
+
 impl Plugin for PhysicsPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugin(RapierPlugin);